@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::result::{RunMetadata, RunResult};
+use crate::stats::OperationSummary;
+
+// 표의 한 줄 (backend의 최신 결과에서 operation 하나)
+struct Row<'a> {
+    backend: &'a str,
+    operation: &'a str,
+    label: String,
+    result: &'a RunResult,
+    duration_secs: f64,
+    summary: &'a OperationSummary,
+    // 같은 backend의 직전 실행 결과
+    previous: Option<&'a OperationSummary>,
+}
+
+/// backend별 최신 결과로 markdown 표와 비교 표를 만듦
+/// (phase 결과가 하나도 없으면 None)
+pub fn render(results: &[RunResult], phase: &str, baseline: Option<&str>) -> Option<String> {
+    let mut results: Vec<&RunResult> = results.iter().collect();
+    // timestamp는 RFC 3339 UTC이므로 문자열 순서가 시간 순서
+    results.sort_by(|a, b| a.metadata.timestamp.cmp(&b.metadata.timestamp));
+
+    let mut by_backend: BTreeMap<&str, Vec<&RunResult>> = BTreeMap::new();
+    for result in results {
+        by_backend
+            .entry(&result.metadata.backend)
+            .or_default()
+            .push(result);
+    }
+
+    let mut rows = vec![];
+    for (backend, runs) in &by_backend {
+        let latest = runs[runs.len() - 1];
+        let previous = runs[..runs.len() - 1]
+            .iter()
+            .rev()
+            .find_map(|run| run.phase(phase));
+
+        let Some(latest_phase) = latest.phase(phase) else {
+            continue;
+        };
+
+        for (operation, summary) in &latest_phase.operations {
+            let label = if latest_phase.operations.len() > 1 {
+                format!("{} ({})", backend, operation)
+            } else {
+                backend.to_string()
+            };
+
+            rows.push(Row {
+                backend,
+                operation,
+                label,
+                result: latest,
+                duration_secs: latest_phase.duration_secs,
+                summary,
+                previous: previous.and_then(|phase| phase.operations.get(operation)),
+            });
+        }
+    }
+
+    if rows.is_empty() {
+        return None;
+    }
+
+    rows.sort_by(|a, b| {
+        b.summary
+            .row_throughput
+            .total_cmp(&a.summary.row_throughput)
+    });
+
+    let mut output = String::new();
+    write_table(&mut output, &rows);
+    output.push('\n');
+    write_comparison(&mut output, &rows, baseline);
+    write_condition_warnings(&mut output, &rows);
+
+    Some(output)
+}
+
+fn write_table(output: &mut String, rows: &[Row]) {
+    output.push_str(
+        "| DB | Records | Concurrency | Duration | TPS | Average Latency | p99 Latency | Max Latency |\n",
+    );
+    output.push_str(
+        "| -- | ------- | ----------- | -------- | --- | --------------- | ----------- | ----------- |\n",
+    );
+
+    for row in rows {
+        let latency = row.summary.latency.as_ref();
+
+        let _ = writeln!(
+            output,
+            "| {} | {} | {} | {:.0} s | {:.0} | {} | {} | {} |",
+            row.label,
+            row.result.metadata.record_count,
+            row.result.metadata.concurrency,
+            row.duration_secs,
+            row.summary.row_throughput,
+            format_ms(latency.map(|latency| latency.mean_ms)),
+            format_ms(latency.map(|latency| latency.p99_ms)),
+            format_ms(latency.map(|latency| latency.max_ms)),
+        );
+    }
+}
+
+fn write_comparison(output: &mut String, rows: &[Row], baseline: Option<&str>) {
+    // baseline을 지정하지 않으면 가장 빠른 backend (rows는 TPS 내림차순)
+    let baseline = baseline.unwrap_or(rows[0].backend);
+
+    let _ = writeln!(output, "Compared to {}:\n", baseline);
+    let _ = writeln!(
+        output,
+        "| DB | TPS | TPS vs {0} | p99 vs {0} | TPS vs previous run |",
+        baseline
+    );
+    output.push_str("| -- | --- | ---------- | ---------- | ------------------- |\n");
+
+    for row in rows {
+        let baseline_row = rows
+            .iter()
+            .find(|other| other.backend == baseline && other.operation == row.operation);
+
+        let tps_ratio = baseline_row
+            .map(|other| ratio(row.summary.row_throughput, other.summary.row_throughput))
+            .unwrap_or_else(|| "-".to_string());
+
+        let p99_ratio = baseline_row
+            .and_then(|other| {
+                let p99 = row.summary.latency.as_ref()?.p99_ms;
+                let baseline_p99 = other.summary.latency.as_ref()?.p99_ms;
+                Some(ratio(p99, baseline_p99))
+            })
+            .unwrap_or_else(|| "-".to_string());
+
+        let previous = row
+            .previous
+            .map(|previous| {
+                let change = row.summary.row_throughput / previous.row_throughput - 1.0;
+                format!("{:+.1}%", change * 100.0)
+            })
+            .unwrap_or_else(|| "-".to_string());
+
+        let _ = writeln!(
+            output,
+            "| {} | {:.0} | {} | {} | {} |",
+            row.label, row.summary.row_throughput, tps_ratio, p99_ratio, previous
+        );
+    }
+}
+
+// dataset, record 수, durability나 index 수가 다르면 비교가 의미 없으므로 경고
+fn write_condition_warnings(output: &mut String, rows: &[Row]) {
+    let first = &rows[0].result.metadata;
+
+    for row in rows {
+        let metadata = &row.result.metadata;
+
+        if metadata.dataset_sha256 != first.dataset_sha256 {
+            let _ = writeln!(
+                output,
+                "\n> Warning: {} used a different dataset ({}) than {}",
+                metadata.backend, metadata.dataset, first.backend
+            );
+        }
+
+        if metadata.record_count != first.record_count {
+            let _ = writeln!(
+                output,
+                "\n> Warning: {} loaded {} records, {} loaded {}",
+                metadata.backend, metadata.record_count, first.backend, first.record_count
+            );
+        }
+
+        if metadata.durability != first.durability {
+            let durability = |metadata: &RunMetadata| {
+                metadata
+                    .durability
+                    .map_or("the backend default", |durability| durability.name())
+            };
+            let _ = writeln!(
+                output,
+                "\n> Warning: {} ran with durability {}, {} with {}",
+                metadata.backend,
+                durability(metadata),
+                first.backend,
+                durability(first)
+            );
+        }
+
+        if metadata.indexes != first.indexes {
+            let _ = writeln!(
+                output,
+                "\n> Warning: {} ran with {} secondary indexes, {} with {}",
+                metadata.backend, metadata.indexes, first.backend, first.indexes
+            );
+        }
+    }
+}
+
+fn ratio(value: f64, baseline: f64) -> String {
+    if baseline == 0.0 {
+        return "-".to_string();
+    }
+
+    format!("{:.2}x", value / baseline)
+}
+
+// README 표와 같이 ms 단위 (1 ms 미만은 소수점 표시)
+fn format_ms(value: Option<f64>) -> String {
+    match value {
+        Some(value) if value < 1.0 => format!("{:.2} ms", value),
+        Some(value) => format!("{:.0} ms", value),
+        None => "-".to_string(),
+    }
+}
@@ -0,0 +1,257 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+use parquet::file::reader::SerializedFileReader;
+use parquet::record::Field;
+
+use crate::workload::WriteEntry;
+
+/// dataset 파일 형식 (지정하지 않으면 확장자로 판단)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    // key,value (RFC 4180 quoting, "key,value" header는 건너뜀)
+    Csv,
+    // 한 줄에 {"key": ..., "value": ...} 하나
+    Jsonl,
+    // key, value column
+    Parquet,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Compression {
+    None,
+    Gzip,
+    Zstd,
+}
+
+/// 읽을 수 없는 record (line은 CSV/JSON Lines의 줄 번호, Parquet의 row 번호)
+#[derive(Clone, Debug)]
+pub struct Malformed {
+    pub line: u64,
+    pub reason: String,
+}
+
+pub type Record = Result<WriteEntry, Malformed>;
+
+/// dataset.csv.gz, dataset.jsonl.zst 처럼 압축 확장자를 떼고 형식을 판단
+fn detect(path: &Path) -> (Option<Format>, Compression) {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    let (name, compression) = if let Some(name) = name.strip_suffix(".gz") {
+        (name, Compression::Gzip)
+    } else if let Some(name) = name.strip_suffix(".zst") {
+        (name, Compression::Zstd)
+    } else {
+        (name.as_str(), Compression::None)
+    };
+
+    let format = match name.rsplit_once('.').map(|(_, extension)| extension) {
+        Some("csv") => Some(Format::Csv),
+        Some("jsonl") | Some("ndjson") => Some(Format::Jsonl),
+        Some("parquet") => Some(Format::Parquet),
+        _ => None,
+    };
+
+    (format, compression)
+}
+
+/// dataset을 한 record씩 읽는 iterator (파일 전체를 메모리에 올리지 않음)
+pub fn open(
+    path: &Path,
+    format: Option<Format>,
+) -> std::io::Result<Box<dyn Iterator<Item = Record> + Send>> {
+    let (detected, compression) = detect(path);
+    let format = format.or(detected).unwrap_or(Format::Csv);
+
+    if format == Format::Parquet {
+        if compression != Compression::None {
+            return Err(std::io::Error::other(
+                "Parquet files are compressed internally and cannot be gzip/zstd-compressed",
+            ));
+        }
+
+        return open_parquet(path);
+    }
+
+    let file = File::open(path)?;
+    let reader: Box<dyn Read + Send> = match compression {
+        Compression::None => Box::new(file),
+        Compression::Gzip => Box::new(flate2::read::MultiGzDecoder::new(file)),
+        Compression::Zstd => Box::new(zstd::Decoder::new(file)?),
+    };
+
+    Ok(match format {
+        Format::Csv => open_csv(reader),
+        Format::Jsonl => open_jsonl(BufReader::new(reader)),
+        Format::Parquet => unreachable!(),
+    })
+}
+
+fn open_csv(reader: Box<dyn Read + Send>) -> Box<dyn Iterator<Item = Record> + Send> {
+    let reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(reader);
+
+    let records = reader
+        .into_records()
+        .enumerate()
+        .filter_map(|(index, record)| {
+            // quoted field 안에 줄바꿈이 있을 수 있으므로 csv가 알려주는 줄 번호 사용
+            let record = match record {
+                Ok(record) => record,
+                Err(error) => {
+                    return Some(Err(Malformed {
+                        line: error
+                            .position()
+                            .map_or(index as u64 + 1, |position| position.line()),
+                        reason: error.to_string(),
+                    }));
+                }
+            };
+            let line = record
+                .position()
+                .map_or(index as u64 + 1, |position| position.line());
+
+            if index == 0 && record.len() == 2 && &record[0] == "key" && &record[1] == "value" {
+                return None;
+            }
+
+            Some(match (record.len(), record.get(0), record.get(1)) {
+                (2, Some(key), Some(value)) if !key.is_empty() => Ok(WriteEntry {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                }),
+                (2, _, _) => Err(Malformed {
+                    line,
+                    reason: "empty key".into(),
+                }),
+                (count, _, _) => Err(Malformed {
+                    line,
+                    reason: format!("expected 2 fields, found {}", count),
+                }),
+            })
+        });
+
+    Box::new(records)
+}
+
+fn open_jsonl(reader: BufReader<Box<dyn Read + Send>>) -> Box<dyn Iterator<Item = Record> + Send> {
+    // I/O error가 나면 lines()는 계속 Err를 반환하므로 (잘린 압축 파일 등) 첫 error만 보고하고 끝냄
+    let mut failed = false;
+    let lines = reader.lines().map_while(move |line| {
+        if failed {
+            return None;
+        }
+        failed = line.is_err();
+        Some(line)
+    });
+
+    let records = lines.enumerate().filter_map(|(index, line)| {
+        let number = index as u64 + 1;
+        let malformed = |reason: String| {
+            Some(Err(Malformed {
+                line: number,
+                reason,
+            }))
+        };
+
+        let line = match line {
+            Ok(line) => line,
+            Err(error) => return malformed(error.to_string()),
+        };
+        if line.trim().is_empty() {
+            return None;
+        }
+
+        let object: serde_json::Value = match serde_json::from_str(&line) {
+            Ok(object) => object,
+            Err(error) => return malformed(error.to_string()),
+        };
+
+        // value가 문자열이 아니면 JSON 그대로 저장
+        let value = match &object["value"] {
+            serde_json::Value::Null => return malformed("missing \"value\"".into()),
+            serde_json::Value::String(value) => value.clone(),
+            value => value.to_string(),
+        };
+
+        match object["key"].as_str() {
+            Some(key) if !key.is_empty() => Some(Ok(WriteEntry {
+                key: key.to_string(),
+                value,
+            })),
+            _ => malformed("missing \"key\" string".into()),
+        }
+    });
+
+    Box::new(records)
+}
+
+fn open_parquet(path: &Path) -> std::io::Result<Box<dyn Iterator<Item = Record> + Send>> {
+    let reader = SerializedFileReader::try_from(path).map_err(std::io::Error::other)?;
+
+    let records = reader.into_iter().enumerate().map(|(index, row)| {
+        let line = index as u64 + 1;
+        let row = row.map_err(|error| Malformed {
+            line,
+            reason: error.to_string(),
+        })?;
+
+        let mut key = None;
+        let mut value = None;
+        for (name, field) in row.into_columns() {
+            let text = match field {
+                Field::Null => continue,
+                Field::Str(text) => text,
+                field => field.to_string(),
+            };
+
+            match name.as_str() {
+                "key" => key = Some(text),
+                "value" => value = Some(text),
+                _ => {}
+            }
+        }
+
+        match (key, value) {
+            (Some(key), Some(value)) if !key.is_empty() => Ok(WriteEntry { key, value }),
+            _ => Err(Malformed {
+                line,
+                reason: "missing key or value column".into(),
+            }),
+        }
+    });
+
+    Ok(Box::new(records))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn truncated_gzip_jsonl_reports_one_error_and_ends() {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        for i in 0..10_000 {
+            writeln!(encoder, r#"{{"key": "key-{i}", "value": "value-{i}"}}"#).unwrap();
+        }
+        let compressed = encoder.finish().unwrap();
+
+        let path =
+            std::env::temp_dir().join(format!("dataset-truncated-{}.jsonl.gz", std::process::id()));
+        std::fs::write(&path, &compressed[..compressed.len() / 2]).unwrap();
+        let records: Vec<Record> = open(&path, None).unwrap().collect();
+        std::fs::remove_file(&path).unwrap();
+
+        let errors = records.iter().filter(|record| record.is_err()).count();
+        assert_eq!(errors, 1);
+        assert!(records.last().unwrap().is_err());
+        assert!(records.len() > 1);
+    }
+}
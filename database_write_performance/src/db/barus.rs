@@ -3,7 +3,8 @@ use serde_json::json;
 use std::sync::Arc;
 use tokio::time::{Duration, sleep};
 
-use super::{Database, Errors, Result};
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
 
 #[derive(Debug)]
 pub struct BarusDB {
@@ -13,15 +14,15 @@ pub struct BarusDB {
 }
 
 impl BarusDB {
-    pub async fn new() -> Result<Arc<dyn Database + Send + Sync>> {
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
         let db = BarusDB {
             client: Client::builder()
                 .tcp_keepalive(Some(Duration::from_secs(30)))
-                .pool_max_idle_per_host(1000)
+                .pool_max_idle_per_host(config.pool_size_or(1000) as usize)
                 .build()
-                .unwrap(),
-            base_url: "http://localhost:53000".to_string(),
-            table_name: "benchmark_kv".to_string(),
+                .map_err(|e| Errors::from_reqwest(e, Errors::ConnectionError))?,
+            base_url: config.http_url_or("http://localhost:53000"),
+            table_name: config.table_or("benchmark_kv"),
         };
 
         Ok(Arc::new(db))
@@ -46,7 +47,7 @@ impl BarusDB {
             .get(&url)
             .send()
             .await
-            .map_err(|e| Errors::ConnectionError(e.to_string()))?;
+            .map_err(|e| Errors::from_reqwest(e, Errors::ConnectionError))?;
 
         if response.status().is_success() {
             Ok(())
@@ -62,7 +63,11 @@ impl Database for BarusDB {
         self.wait_for_connection().await
     }
 
-    async fn setup(&self) -> Result<()> {
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        if let Some(durability) = durability {
+            return Err(durability.unsupported("BarusDB", "durability is not configurable"));
+        }
+
         let url = format!("{}/tables/{}", self.base_url, self.table_name);
         let response = self
             .client
@@ -71,7 +76,7 @@ impl Database for BarusDB {
             .body("{}")
             .send()
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_reqwest(e, Errors::WriteError))?;
 
         if !response.status().is_success() {
             if response.status().as_u16() == 409 {
@@ -100,15 +105,49 @@ impl Database for BarusDB {
             .json(&doc)
             .send()
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_reqwest(e, Errors::WriteError))?;
 
         if !response.status().is_success() {
-            return Err(Errors::WriteError("Failed to insert document".into()));
+            return Err(Errors::from_status(
+                response.status(),
+                "Failed to insert document",
+                Errors::WriteError,
+            ));
         }
 
         Ok(())
     }
 
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let url = format!("{}/tables/{}/value", self.base_url, self.table_name);
+        let response = self
+            .client
+            .get(&url)
+            .query(&[("key", key)])
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ReadError))?;
+
+        if response.status().as_u16() == 404 {
+            return Ok(None);
+        }
+
+        if !response.status().is_success() {
+            return Err(Errors::from_status(
+                response.status(),
+                "Failed to get document",
+                Errors::ReadError,
+            ));
+        }
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ReadError))?;
+
+        Ok(body["value"].as_str().map(|value| value.to_string()))
+    }
+
     fn worker_count(&self) -> usize {
         1000
     }
@@ -2,7 +2,8 @@ use std::sync::Arc;
 use tokio::time::{Duration, sleep};
 use tonic::transport::Channel;
 
-use super::{Database, Errors, Result};
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
 
 // Include the generated proto code
 pub mod barus {
@@ -10,7 +11,7 @@ pub mod barus {
 }
 
 use barus::barus_service_client::BarusServiceClient;
-use barus::{HealthRequest, PutRequest};
+use barus::{GetRequest, HealthRequest, PutRequest};
 
 #[derive(Debug, Clone)]
 pub struct BarusDBGrpc {
@@ -19,13 +20,16 @@ pub struct BarusDBGrpc {
 }
 
 impl BarusDBGrpc {
-    pub async fn new() -> Result<Arc<dyn Database + Send + Sync>> {
-        let channel = Channel::from_static("http://localhost:53001")
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
+        config.reject_tls("Barus gRPC")?;
+
+        let channel = Channel::from_shared(config.url_or("http://localhost:53001"))
+            .map_err(|e| Errors::ConnectionError(format!("Invalid url: {}", e)))?
             .http2_keep_alive_interval(Duration::from_secs(30))
             .keep_alive_timeout(Duration::from_secs(10))
             .keep_alive_while_idle(true)
             .tcp_keepalive(Some(Duration::from_secs(30)))
-            .concurrency_limit(1000)
+            .concurrency_limit(config.pool_size_or(1000) as usize)
             .connect_timeout(Duration::from_secs(5))
             .connect()
             .await
@@ -33,7 +37,7 @@ impl BarusDBGrpc {
 
         let db = BarusDBGrpc {
             channel,
-            table_name: Arc::new("benchmark_kv".to_string()),
+            table_name: Arc::new(config.table_or("benchmark_kv")),
         };
 
         Ok(Arc::new(db))
@@ -72,7 +76,11 @@ impl Database for BarusDBGrpc {
         self.wait_for_connection().await
     }
 
-    async fn setup(&self) -> Result<()> {
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        if let Some(durability) = durability {
+            return Err(durability.unsupported("BarusDB", "durability is not configurable"));
+        }
+
         Ok(())
     }
 
@@ -85,14 +93,36 @@ impl Database for BarusDBGrpc {
             value: value.to_string(),
         });
 
-        client
-            .put(request)
-            .await
-            .map_err(|e| Errors::WriteError(format!("gRPC put failed: {}", e)))?;
+        client.put(request).await.map_err(|e| {
+            Errors::from_grpc(
+                e.code() as i32,
+                format!("gRPC put failed: {}", e),
+                Errors::WriteError,
+            )
+        })?;
 
         Ok(())
     }
 
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let mut client = BarusServiceClient::new(self.channel.clone());
+
+        let request = tonic::Request::new(GetRequest {
+            table: self.table_name.as_ref().clone(),
+            key: key.to_string(),
+        });
+
+        match client.get(request).await {
+            Ok(response) => Ok(Some(response.into_inner().value)),
+            Err(status) if status.code() == tonic::Code::NotFound => Ok(None),
+            Err(e) => Err(Errors::from_grpc(
+                e.code() as i32,
+                format!("gRPC get failed: {}", e),
+                Errors::ReadError,
+            )),
+        }
+    }
+
     fn worker_count(&self) -> usize {
         1000
     }
@@ -1,22 +1,207 @@
+use scylla::batch::{Batch, BatchType};
+use scylla::query::Query;
+use scylla::statement::Consistency;
 use scylla::{Session, SessionBuilder};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
 
-use super::{Database, Errors, Result};
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
+use crate::record::Schema;
+
+/// durability에 해당하는 (replication factor, durable_writes, 쓰기 consistency)
+///
+/// commitlog fsync 주기(commitlog_sync)는 node 설정이라 fsync는 맞출 수 없음
+pub(super) async fn keyspace_durability(
+    session: &Session,
+    durability: Durability,
+    backend: &str,
+) -> Result<(usize, bool, Consistency)> {
+    match durability {
+        // durable_writes = false면 commitlog를 건너뛰고 memtable에만 씀
+        Durability::Memory => Ok((1, false, Consistency::One)),
+        Durability::OsBuffered => Ok((1, true, Consistency::One)),
+        Durability::Fsync => {
+            Err(durability.unsupported(backend, "commitlog_sync is a node option"))
+        }
+        Durability::Replicated => {
+            let peers = session
+                .query_unpaged("SELECT peer FROM system.peers", ())
+                .await
+                .map_err(|e| Errors::ConnectionError(e.to_string()))?
+                .into_rows_result()
+                .map_err(|e| Errors::ConnectionError(e.to_string()))?
+                .rows_num();
+            if peers == 0 {
+                return Err(durability.unsupported(backend, "the cluster has a single node"));
+            }
+
+            Ok(((peers + 1).min(3), true, Consistency::Quorum))
+        }
+    }
+}
+
+// batch_size_fail_threshold (기본 50 KiB)보다 작게 유지할 batch 하나의 크기
+const MAX_BATCH_BYTES: usize = 32 * 1024;
+// batch 하나에 담는 최대 statement 수
+const MAX_BATCH_STATEMENTS: usize = 100;
+
+/// entries를 MAX_BATCH_STATEMENTS개, MAX_BATCH_BYTES 이하씩 나눔 (row 하나가 더 크면 단독으로)
+fn batch_chunks(entries: &[(String, String)], field_count: usize) -> Vec<&[(String, String)]> {
+    let mut chunks = vec![];
+    let mut start = 0;
+    let mut bytes = 0;
+
+    for (i, (key, value)) in entries.iter().enumerate() {
+        let size = key.len() + value.len() + field_count * super::INDEX_SLICE_CHARS;
+        if i > start && (i - start >= MAX_BATCH_STATEMENTS || bytes + size > MAX_BATCH_BYTES) {
+            chunks.push(&entries[start..i]);
+            start = i;
+            bytes = 0;
+        }
+        bytes += size;
+    }
+    if start < entries.len() {
+        chunks.push(&entries[start..]);
+    }
+
+    chunks
+}
+
+/// entries를 나눠서 unlogged batch로 순서대로 보냄
+///
+/// 서로 다른 partition에 쓰므로 batch log를 남기지 않는 unlogged batch를 사용하고,
+/// 큰 --batch-size가 batch_size_fail_threshold에 걸리지 않도록 batch_chunks 단위로 나눔
+pub(super) async fn write_unlogged_batch(
+    session: &Session,
+    query: Query,
+    entries: &[(String, String)],
+    field_count: usize,
+) -> Result<()> {
+    for chunk in batch_chunks(entries, field_count) {
+        let mut batch = Batch::new(BatchType::Unlogged);
+        for _ in chunk {
+            batch.append_statement(query.clone());
+        }
+        if let Some(consistency) = query.get_consistency() {
+            batch.set_consistency(consistency);
+        }
+
+        let result = if field_count > 0 {
+            let values: Vec<Vec<String>> = chunk
+                .iter()
+                .map(|(key, value)| row_values(key, value, field_count))
+                .collect();
+            session.batch(&batch, values).await
+        } else {
+            session.batch(&batch, chunk).await
+        };
+        result.map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
+    }
+
+    Ok(())
+}
+
+/// key, value와 value 조각 column (value_<i>)에 쓰는 INSERT
+pub(super) fn insert_statement(table: &str, field_count: usize) -> String {
+    let columns: String = (0..field_count).map(|i| format!(", value_{}", i)).collect();
+    let markers = ", ?".repeat(field_count);
+
+    format!("INSERT INTO {table} (key, value{columns}) VALUES (?, ?{markers})")
+}
+
+/// insert_statement에 bind할 값
+pub(super) fn row_values(key: &str, value: &str, field_count: usize) -> Vec<String> {
+    let mut values = vec![key.to_string(), value.to_string()];
+    values.extend(super::index_slices(value, field_count));
+
+    values
+}
+
+/// CQL에는 expression index가 없으므로 value 조각을 column으로 추가하고 index를 만듦
+///
+/// column은 index 수와 관계없이 field_count개를 추가하고 앞쪽 count개에만 index를 만듦
+/// (using: index class, None이면 기본 secondary index)
+pub(super) async fn create_value_indexes(
+    session: &Session,
+    table: &str,
+    count: usize,
+    field_count: usize,
+    using: Option<&str>,
+) -> Result<()> {
+    if field_count == 0 {
+        return Ok(());
+    }
+
+    let columns: Vec<String> = (0..field_count)
+        .map(|i| format!("value_{} TEXT", i))
+        .collect();
+    session
+        .query_unpaged(
+            format!("ALTER TABLE {} ADD ({})", table, columns.join(", ")),
+            (),
+        )
+        .await
+        .map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
+
+    let using = using.map_or(String::new(), |using| format!(" USING '{}'", using));
+    for i in 0..count {
+        session
+            .query_unpaged(
+                format!("CREATE INDEX ON {} (value_{}){}", table, i, using),
+                (),
+            )
+            .await
+            .map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
+    }
+
+    Ok(())
+}
 
 #[derive(Debug)]
 pub struct ScyllaDB {
     session: Session,
+    keyspace: String,
+    table: String,
+    // setup에서 durability로 정함 (None이면 driver 기본값)
+    consistency: RwLock<Option<Consistency>>,
+    // 같이 저장하는 value 조각 column 수 (--indexes의 최댓값, index는 앞쪽 일부에만 있음)
+    field_count: AtomicUsize,
 }
 
 impl ScyllaDB {
-    pub async fn new() -> Result<Arc<dyn Database + Send + Sync>> {
-        let session = SessionBuilder::new()
-            .known_node("127.0.0.1:19043")
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
+        config.reject_tls("ScyllaDB")?;
+
+        let mut builder = SessionBuilder::new().known_node(config.url_or("127.0.0.1:19043"));
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            builder = builder.user(username, password);
+        }
+
+        let session = builder
             .build()
             .await
             .map_err(|error| Errors::ConnectionError(error.to_string()))?;
 
-        Ok(Arc::new(ScyllaDB { session }))
+        Ok(Arc::new(ScyllaDB {
+            session,
+            keyspace: config.database_or("benchmark"),
+            table: config.table_or("key_value"),
+            consistency: RwLock::new(None),
+            field_count: AtomicUsize::new(0),
+        }))
+    }
+
+    fn write_query(&self) -> Query {
+        let mut query = Query::new(insert_statement(
+            &self.table,
+            self.field_count.load(Ordering::Relaxed),
+        ));
+        if let Some(consistency) = *self.consistency.read().unwrap() {
+            query.set_consistency(consistency);
+        }
+
+        query
     }
 }
 
@@ -32,56 +217,160 @@ impl Database for ScyllaDB {
         Ok(())
     }
 
-    async fn setup(&self) -> Result<()> {
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        let (replication_factor, durable_writes, consistency) = match durability {
+            Some(durability) => {
+                let (replication_factor, durable_writes, consistency) =
+                    keyspace_durability(&self.session, durability, "ScyllaDB").await?;
+                (replication_factor, durable_writes, Some(consistency))
+            }
+            None => (1, true, None),
+        };
+        *self.consistency.write().unwrap() = consistency;
+
+        let options = format!(
+            "WITH REPLICATION = {{
+                 'class': 'SimpleStrategy',
+                 'replication_factor': {replication_factor}
+             }} AND DURABLE_WRITES = {durable_writes}"
+        );
+
         // 키스페이스 생성
         self.session
             .query_unpaged(
-                "CREATE KEYSPACE IF NOT EXISTS benchmark 
-                 WITH REPLICATION = {
-                     'class': 'SimpleStrategy',
-                     'replication_factor': 1
-                 }",
+                format!(
+                    "CREATE KEYSPACE IF NOT EXISTS {keyspace} {options}",
+                    keyspace = self.keyspace
+                ),
                 (),
             )
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
+
+        // 이미 있던 keyspace에도 durability 설정을 적용
+        if durability.is_some() {
+            self.session
+                .query_unpaged(
+                    format!(
+                        "ALTER KEYSPACE {keyspace} {options}",
+                        keyspace = self.keyspace
+                    ),
+                    (),
+                )
+                .await
+                .map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
+        }
 
         // 키스페이스 사용
         self.session
-            .use_keyspace("benchmark", false)
+            .use_keyspace(&self.keyspace, false)
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
 
         // 기존 테이블 삭제 (있다면)
         self.session
-            .query_unpaged("DROP TABLE IF EXISTS key_value", ())
+            .query_unpaged(
+                format!("DROP TABLE IF EXISTS {table}", table = self.table),
+                (),
+            )
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
 
         // 새 테이블 생성
         self.session
             .query_unpaged(
-                "CREATE TABLE key_value (
+                format!(
+                    "CREATE TABLE {table} (
                     key TEXT PRIMARY KEY,
                     value TEXT
                 )",
+                    table = self.table
+                ),
                 (),
             )
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
+
+        Ok(())
+    }
+
+    async fn create_indexes(
+        &self,
+        count: usize,
+        field_count: usize,
+        _schema: Option<&Schema>,
+    ) -> Result<()> {
+        create_value_indexes(&self.session, &self.table, count, field_count, None).await?;
+        self.field_count.store(field_count, Ordering::Relaxed);
 
         Ok(())
     }
 
     async fn write(&self, key: &str, value: &str) -> Result<()> {
-        self.session
+        let field_count = self.field_count.load(Ordering::Relaxed);
+        let result = if field_count > 0 {
+            let values = row_values(key, value, field_count);
+            self.session.query_unpaged(self.write_query(), values).await
+        } else {
+            self.session
+                .query_unpaged(self.write_query(), (key, value))
+                .await
+        };
+        result.map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
+
+        Ok(())
+    }
+
+    async fn write_batch(&self, entries: &[(String, String)]) -> Result<()> {
+        let field_count = self.field_count.load(Ordering::Relaxed);
+        write_unlogged_batch(&self.session, self.write_query(), entries, field_count).await
+    }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let result = self
+            .session
             .query_unpaged(
-                "INSERT INTO key_value (key, value) VALUES (?, ?)",
-                (key, value),
+                format!(
+                    "SELECT value FROM {table} WHERE key = ?",
+                    table = self.table
+                ),
+                (key,),
             )
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_scylla(e, Errors::ReadError))?
+            .into_rows_result()
+            .map_err(|e| Errors::ReadError(e.to_string()))?;
 
-        Ok(())
+        let row = result
+            .maybe_first_row::<(Option<String>,)>()
+            .map_err(|e| Errors::ReadError(e.to_string()))?;
+
+        Ok(row.and_then(|(value,)| value))
+    }
+
+    async fn scan(&self, start_key: &str, count: usize) -> Result<Vec<(String, String)>> {
+        // partition key는 hash되므로 token 순서로 range scan
+        let result = self
+            .session
+            .query_unpaged(
+                format!(
+                    "SELECT key, value FROM {table} WHERE token(key) >= token(?) LIMIT ?",
+                    table = self.table
+                ),
+                (start_key, count as i32),
+            )
+            .await
+            .map_err(|e| Errors::from_scylla(e, Errors::ReadError))?
+            .into_rows_result()
+            .map_err(|e| Errors::ReadError(e.to_string()))?;
+
+        let rows = result
+            .rows::<(String, Option<String>)>()
+            .map_err(|e| Errors::ReadError(e.to_string()))?
+            .map(|row| row.map(|(key, value)| (key, value.unwrap_or_default())))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| Errors::ReadError(e.to_string()))?;
+
+        Ok(rows)
     }
 }
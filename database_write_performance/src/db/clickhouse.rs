@@ -1,21 +1,107 @@
-use clickhouse::Client;
-use std::sync::Arc;
+use clickhouse::{Client, Row};
+use serde::Serialize;
+use std::sync::{Arc, RwLock};
 
-use super::{Database, Errors, Result};
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
+use crate::record::{FieldType, Record, Schema, Value};
+
+// created_at은 DEFAULT now()로 채워짐
+#[derive(Row, Serialize)]
+struct KeyValueRow<'a> {
+    key: &'a str,
+    value: &'a str,
+}
 
 pub struct ClickHouse {
     client: Client,
+    database: String,
+    table: String,
+    // setup에서 정한 durability
+    durability: RwLock<Option<Durability>>,
 }
 
 impl ClickHouse {
-    pub async fn new() -> Result<Arc<dyn Database + Send + Sync>> {
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
+        let database = config.database_or("benchmark");
+
         let client = Client::default()
-            .with_url("http://127.0.0.1:18123")
-            .with_user("user")
-            .with_password("q1w2e3r4")
-            .with_database("benchmark");
+            .with_url(config.http_url_or("http://127.0.0.1:18123"))
+            .with_user(config.username_or("user"))
+            .with_password(config.password_or("q1w2e3r4"))
+            .with_database(&database);
 
-        Ok(Arc::new(ClickHouse { client }))
+        Ok(Arc::new(ClickHouse {
+            client,
+            database,
+            table: config.table_or("key_value"),
+            durability: RwLock::new(None),
+        }))
+    }
+
+    // memory면 async insert buffer에 들어가자마자 응답 (flush 전에 서버가 죽으면 유실)
+    fn wait_for_async_insert(&self) -> bool {
+        *self.durability.read().unwrap() != Some(Durability::Memory)
+    }
+
+    // record를 JSONEachRow로 한 번에 insert (write와 같이 단건은 항상 async insert)
+    async fn insert_records(
+        &self,
+        schema: &Schema,
+        records: &[Record],
+        async_insert: bool,
+    ) -> Result<()> {
+        let async_settings = if async_insert || !self.wait_for_async_insert() {
+            format!(
+                ", async_insert=1, wait_for_async_insert={}",
+                u8::from(self.wait_for_async_insert())
+            )
+        } else {
+            String::new()
+        };
+
+        // timestamp는 RFC 3339 문자열로 보내므로 best_effort로 parse
+        let mut sql = format!(
+            "INSERT INTO {}.{} SETTINGS date_time_input_format='best_effort'{} FORMAT JSONEachRow\n",
+            self.database, self.table, async_settings
+        );
+        for record in records {
+            let mut row = serde_json::Map::new();
+            row.insert("key".into(), record.key.as_str().into());
+            for (field, value) in schema.fields.iter().zip(&record.values) {
+                let value = match value {
+                    // Nullable column이므로 생략하면 NULL
+                    Value::Null => continue,
+                    // JSON field는 String column에 문자열로 저장
+                    Value::Json(value) => value.to_string().into(),
+                    value => value.to_json(),
+                };
+                row.insert(field.name.clone(), value);
+            }
+            sql.push_str(&serde_json::Value::Object(row).to_string());
+            sql.push('\n');
+        }
+
+        // query의 ?는 bind 자리로 해석되므로 escape
+        self.client
+            .query(&sql.replace('?', "??"))
+            .execute()
+            .await
+            .map_err(|e| Errors::from_clickhouse(e, Errors::WriteError))?;
+
+        Ok(())
+    }
+}
+
+// record field의 column type (dataset에 없는 field가 있으므로 Nullable)
+fn column_type(kind: FieldType, tag: bool) -> &'static str {
+    match kind {
+        FieldType::Int => "Nullable(Int64)",
+        FieldType::Float => "Nullable(Float64)",
+        FieldType::Bool => "Nullable(Bool)",
+        FieldType::Timestamp => "Nullable(DateTime64(3, 'UTC'))",
+        FieldType::Text if tag => "LowCardinality(Nullable(String))",
+        FieldType::Text | FieldType::Json => "Nullable(String)",
     }
 }
 
@@ -32,32 +118,48 @@ impl Database for ClickHouse {
         Ok(())
     }
 
-    async fn setup(&self) -> Result<()> {
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        // part는 기본적으로 fsync 없이 쓰므로 (os-buffered) fsync는 MergeTree 설정으로 켬
+        let fsync_settings = match durability {
+            Some(Durability::Fsync) => ", fsync_after_insert = 1, fsync_part_directory = 1",
+            Some(durability @ Durability::Replicated) => {
+                return Err(
+                    durability.unsupported("ClickHouse", "the table is not a ReplicatedMergeTree")
+                );
+            }
+            _ => "",
+        };
+        *self.durability.write().unwrap() = durability;
+
         // 데이터베이스 생성 (존재하지 않을 경우)
         self.client
-            .query("CREATE DATABASE IF NOT EXISTS benchmark")
+            .query(&format!("CREATE DATABASE IF NOT EXISTS {}", self.database))
             .execute()
             .await
             .map_err(|e| Errors::ConnectionError(e.to_string()))?;
 
         // 테이블이 존재하면 삭제
         self.client
-            .query("DROP TABLE IF EXISTS benchmark.key_value")
+            .query(&format!(
+                "DROP TABLE IF EXISTS {}.{}",
+                self.database, self.table
+            ))
             .execute()
             .await
             .map_err(|e| Errors::ConnectionError(e.to_string()))?;
 
         // 새 테이블 생성 (ClickHouse 최적화 포함)
         self.client
-            .query(
-                "CREATE TABLE benchmark.key_value (
+            .query(&format!(
+                "CREATE TABLE {}.{} (
                     key String,
                     value String,
                     created_at DateTime DEFAULT now()
                 ) ENGINE = MergeTree()
                 ORDER BY key
-                SETTINGS index_granularity = 8192",
-            )
+                SETTINGS index_granularity = 8192{}",
+                self.database, self.table, fsync_settings
+            ))
             .execute()
             .await
             .map_err(|e| Errors::ConnectionError(e.to_string()))?;
@@ -67,13 +169,125 @@ impl Database for ClickHouse {
 
     async fn write(&self, key: &str, value: &str) -> Result<()> {
         self.client
-            .query("INSERT INTO benchmark.key_value (key, value) SETTINGS async_insert=1, wait_for_async_insert=1 VALUES (?, ?)")
+            .query(&format!("INSERT INTO {}.{} (key, value) SETTINGS async_insert=1, wait_for_async_insert={} VALUES (?, ?)", self.database, self.table, u8::from(self.wait_for_async_insert())))
             .bind(key)
             .bind(value)
             .execute()
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_clickhouse(e, Errors::WriteError))?;
+
+        Ok(())
+    }
+
+    async fn write_batch(&self, entries: &[(String, String)]) -> Result<()> {
+        // 한 번의 INSERT로 전체 batch를 하나의 block으로 전송
+        let client = if self.wait_for_async_insert() {
+            self.client.clone()
+        } else {
+            self.client
+                .clone()
+                .with_option("async_insert", "1")
+                .with_option("wait_for_async_insert", "0")
+        };
+        let mut insert = client
+            .insert::<KeyValueRow>(&format!("{}.{}", self.database, self.table))
+            .map_err(|e| Errors::from_clickhouse(e, Errors::WriteError))?;
+
+        for (key, value) in entries {
+            insert
+                .write(&KeyValueRow { key, value })
+                .await
+                .map_err(|e| Errors::from_clickhouse(e, Errors::WriteError))?;
+        }
+
+        insert
+            .end()
+            .await
+            .map_err(|e| Errors::from_clickhouse(e, Errors::WriteError))?;
+
+        Ok(())
+    }
+
+    async fn setup_schema(&self, schema: &Schema) -> Result<()> {
+        // value column 대신 field마다 native column
+        let columns: Vec<String> = schema
+            .fields
+            .iter()
+            .map(|field| {
+                format!(
+                    "ADD COLUMN `{}` {}",
+                    field.name,
+                    column_type(field.kind, field.tag)
+                )
+            })
+            .collect();
+
+        self.client
+            .query(&format!(
+                "ALTER TABLE {}.{} DROP COLUMN value, {}",
+                self.database,
+                self.table,
+                columns.join(", ")
+            ))
+            .execute()
+            .await
+            .map_err(|e| Errors::ConnectionError(e.to_string()))?;
 
         Ok(())
     }
+
+    async fn write_record(&self, schema: &Schema, record: &Record) -> Result<()> {
+        self.insert_records(schema, std::slice::from_ref(record), true)
+            .await
+    }
+
+    async fn write_records(&self, schema: &Schema, records: &[Record]) -> Result<()> {
+        self.insert_records(schema, records, false).await
+    }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let value = self
+            .client
+            .query(&format!(
+                "SELECT value FROM {}.{} WHERE key = ? ORDER BY created_at DESC LIMIT 1",
+                self.database, self.table
+            ))
+            .bind(key)
+            .fetch_optional::<String>()
+            .await
+            .map_err(|e| Errors::from_clickhouse(e, Errors::ReadError))?;
+
+        Ok(value)
+    }
+
+    async fn count(&self) -> Result<u64> {
+        // MergeTree는 같은 key의 row를 모두 보관하므로 중복을 포함한 row 수
+        let count = self
+            .client
+            .query(&format!(
+                "SELECT count() FROM {}.{}",
+                self.database, self.table
+            ))
+            .fetch_one::<u64>()
+            .await
+            .map_err(|e| Errors::from_clickhouse(e, Errors::ReadError))?;
+
+        Ok(count)
+    }
+
+    async fn scan(&self, start_key: &str, count: usize) -> Result<Vec<(String, String)>> {
+        let rows = self
+            .client
+            .query(&format!(
+                "SELECT key, value FROM {}.{} WHERE key >= ? ORDER BY key LIMIT ?",
+                self.database, self.table
+            ))
+            .bind(start_key)
+            .bind(count as u64)
+            .fetch_all::<(String, String)>()
+            .await
+            .map_err(|e| Errors::from_clickhouse(e, Errors::ReadError))?;
+
+        Ok(rows)
+    }
 }
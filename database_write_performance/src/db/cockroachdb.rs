@@ -1,25 +1,49 @@
 use sqlx::{PgPool, postgres::PgPoolOptions};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
-use super::{Database, Errors, Result};
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
+use crate::record::Schema;
 
 #[derive(Debug)]
 pub struct CockroachDB {
     pool: PgPool,
+    // setup이 처음 바꾸기 전의 kv.raft_log.disable_synchronization_unsafe (restore_settings에서 되돌림)
+    saved_disable_sync: RwLock<Option<bool>>,
+    table: String,
 }
 
 impl CockroachDB {
-    pub async fn new() -> Result<Arc<dyn Database + Send + Sync>> {
-        let connection_string = "postgres://root@127.0.0.1:26257/benchmark?sslmode=disable";
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
+        let connection_string = config.url_with_credentials(
+            "postgres://root@127.0.0.1:26257/benchmark?sslmode=disable",
+            ("sslmode", "require"),
+        )?;
 
         let pool = PgPoolOptions::new()
-            .max_connections(1000) // 최대 연결 수
-            .min_connections(1000) // 최소 연결 수
-            .connect(connection_string)
+            .max_connections(config.pool_size_or(1000)) // 최대 연결 수
+            .min_connections(config.min_pool_size_or(1000)) // 최소 연결 수
+            .connect(&connection_string)
             .await
-            .map_err(|error| Errors::ConnectionError(error.to_string()))?;
+            .map_err(|error| Errors::from_sqlx(error, Errors::ConnectionError))?;
 
-        Ok(Arc::new(CockroachDB { pool }))
+        Ok(Arc::new(CockroachDB {
+            pool,
+            saved_disable_sync: RwLock::new(None),
+            table: config.table_or("key_value"),
+        }))
+    }
+
+    async fn set_disable_sync(&self, disable_sync: bool) -> Result<()> {
+        sqlx::query(&format!(
+            "SET CLUSTER SETTING kv.raft_log.disable_synchronization_unsafe = {}",
+            disable_sync
+        ))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Errors::from_sqlx(e, Errors::ConnectionError))?;
+
+        Ok(())
     }
 }
 
@@ -29,46 +53,132 @@ impl Database for CockroachDB {
         sqlx::query("SELECT 1")
             .execute(&self.pool)
             .await
-            .map_err(|error| Errors::ConnectionError(error.to_string()))?;
+            .map_err(|error| Errors::from_sqlx(error, Errors::ConnectionError))?;
         Ok(())
     }
 
-    async fn setup(&self) -> Result<()> {
+    async fn restore_settings(&self) -> Result<()> {
+        let original = self.saved_disable_sync.write().unwrap().take();
+        match original {
+            Some(disable_sync) => self.set_disable_sync(disable_sync).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        // Raft log를 fsync하고 quorum에 복제한 뒤 응답함 (os-buffered는 fsync를 끄는 cluster setting)
+        let disable_sync = match durability {
+            None => None,
+            Some(durability @ Durability::Memory) => {
+                return Err(
+                    durability.unsupported("CockroachDB", "writes always go to the Raft log")
+                );
+            }
+            Some(Durability::OsBuffered) => Some(true),
+            Some(Durability::Fsync | Durability::Replicated) => Some(false),
+        };
+        if let Some(disable_sync) = disable_sync {
+            // cluster 전체 설정이므로 처음 값을 남겨 둠 (sweep 등으로 setup을 다시 해도 유지)
+            if self.saved_disable_sync.read().unwrap().is_none() {
+                let original = sqlx::query_scalar::<_, bool>(
+                    "SHOW CLUSTER SETTING kv.raft_log.disable_synchronization_unsafe",
+                )
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| Errors::from_sqlx(e, Errors::ConnectionError))?;
+                *self.saved_disable_sync.write().unwrap() = Some(original);
+            }
+
+            self.set_disable_sync(disable_sync).await?;
+        }
+
         // 데이터베이스 생성 (존재하지 않을 경우)
         sqlx::query("CREATE DATABASE IF NOT EXISTS benchmark")
             .execute(&self.pool)
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_sqlx(e, Errors::WriteError))?;
 
         // 테이블이 존재하면 삭제
-        sqlx::query("DROP TABLE IF EXISTS key_value")
+        sqlx::query(&format!("DROP TABLE IF EXISTS {table}", table = self.table))
             .execute(&self.pool)
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_sqlx(e, Errors::WriteError))?;
 
         // 새 테이블 생성 (CockroachDB 최적화 포함)
-        sqlx::query(
-            "CREATE TABLE key_value (
+        sqlx::query(&format!(
+            "CREATE TABLE {table} (
                 key STRING PRIMARY KEY,
                 value STRING NOT NULL
             )",
-        )
+            table = self.table
+        ))
         .execute(&self.pool)
         .await
-        .map_err(|e| Errors::WriteError(e.to_string()))?;
+        .map_err(|e| Errors::from_sqlx(e, Errors::WriteError))?;
 
         Ok(())
     }
 
+    async fn create_indexes(
+        &self,
+        count: usize,
+        _field_count: usize,
+        _schema: Option<&Schema>,
+    ) -> Result<()> {
+        super::postgres::create_value_indexes(&self.pool, &self.table, count).await
+    }
+
     async fn write(&self, key: &str, value: &str) -> Result<()> {
         // CockroachDB의 UPSERT 구문 사용
-        sqlx::query("UPSERT INTO key_value (key, value) VALUES ($1, $2)")
-            .bind(key)
-            .bind(value)
-            .execute(&self.pool)
-            .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+        sqlx::query(&format!(
+            "UPSERT INTO {table} (key, value) VALUES ($1, $2)",
+            table = self.table
+        ))
+        .bind(key)
+        .bind(value)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Errors::from_sqlx(e, Errors::WriteError))?;
 
         Ok(())
     }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let value = sqlx::query_scalar::<_, String>(&format!(
+            "SELECT value FROM {table} WHERE key = $1",
+            table = self.table
+        ))
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Errors::from_sqlx(e, Errors::ReadError))?;
+
+        Ok(value)
+    }
+
+    async fn count(&self) -> Result<u64> {
+        let count = sqlx::query_scalar::<_, i64>(&format!(
+            "SELECT COUNT(*) FROM {table}",
+            table = self.table
+        ))
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Errors::from_sqlx(e, Errors::ReadError))?;
+
+        Ok(count as u64)
+    }
+
+    async fn scan(&self, start_key: &str, count: usize) -> Result<Vec<(String, String)>> {
+        let rows = sqlx::query_as::<_, (String, String)>(&format!(
+            "SELECT key, value FROM {table} WHERE key >= $1 ORDER BY key LIMIT $2",
+            table = self.table
+        ))
+        .bind(start_key)
+        .bind(count as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Errors::from_sqlx(e, Errors::ReadError))?;
+
+        Ok(rows)
+    }
 }
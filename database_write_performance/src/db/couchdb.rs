@@ -1,10 +1,18 @@
 use std::borrow::Cow;
 use std::sync::Arc;
 
-use couch_rs::{Client, database::Database, document::TypedCouchDocument};
+use couch_rs::{
+    Client,
+    database::Database,
+    document::TypedCouchDocument,
+    types::find::{FindQuery, SortSpec},
+    types::index::IndexFields,
+};
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 
-use crate::db::{Database as DbTrait, Errors, Result};
+use crate::config::BackendConfig;
+use crate::db::{Database as DbTrait, Durability, Errors, Result};
 
 #[derive(Clone)]
 pub struct CouchDB {
@@ -56,18 +64,26 @@ impl TypedCouchDocument for BenchmarkDoc {
 }
 
 impl CouchDB {
-    pub async fn new() -> Result<Arc<dyn DbTrait + Send + Sync>> {
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn DbTrait + Send + Sync>> {
         // 연결 설정 개선
-        let client = Client::new("http://localhost:15984", "admin", "q1w2e3r4").map_err(|e| {
+        let client = Client::new(
+            &config.http_url_or("http://localhost:15984"),
+            &config.username_or("admin"),
+            &config.password_or("q1w2e3r4"),
+        )
+        .map_err(|e| {
             eprintln!("CouchDB client creation error: {:?}", e);
             Errors::ConnectionError(format!("CouchDB client creation failed: {}", e))
         })?;
 
         // 데이터베이스 객체를 미리 가져와서 재사용
-        let db = client.db("benchmark").await.map_err(|e| {
-            eprintln!("CouchDB database connection error: {:?}", e);
-            Errors::ConnectionError(format!("CouchDB database connection failed: {}", e))
-        })?;
+        let db = client
+            .db(&config.database_or("benchmark"))
+            .await
+            .map_err(|e| {
+                eprintln!("CouchDB database connection error: {:?}", e);
+                Errors::ConnectionError(format!("CouchDB database connection failed: {}", e))
+            })?;
 
         let couchdb = CouchDB { db };
 
@@ -88,7 +104,18 @@ impl DbTrait for CouchDB {
         }
     }
 
-    async fn setup(&self) -> Result<()> {
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        // 문서마다 fsync한 뒤 응답함 (cluster의 write quorum은 설정하지 않음)
+        match durability {
+            None | Some(Durability::Fsync) => {}
+            Some(durability @ (Durability::Memory | Durability::OsBuffered)) => {
+                return Err(durability.unsupported("CouchDB", "every write is fsynced"));
+            }
+            Some(durability @ Durability::Replicated) => {
+                return Err(durability.unsupported("CouchDB", "the write quorum is not configured"));
+            }
+        }
+
         // CouchDB는 데이터베이스가 자동으로 생성되므로 ping으로 확인만 함
         let exists = self.db.exists("").await;
 
@@ -98,6 +125,17 @@ impl DbTrait for CouchDB {
             eprintln!("CouchDB database not accessible");
         }
 
+        // read에서 key 필드로 조회하므로 인덱스 생성
+        self.db
+            .insert_index(
+                "key-index",
+                IndexFields::new(vec![SortSpec::Simple("key".to_string())]),
+                None,
+                None,
+            )
+            .await
+            .map_err(|e| Errors::from_couchdb(e, Errors::WriteError))?;
+
         Ok(())
     }
 
@@ -120,11 +158,28 @@ impl DbTrait for CouchDB {
             Ok(_) => Ok(()),
             Err(e) => {
                 eprintln!("CouchDB write error: {:?}", e);
-                Err(Errors::WriteError(e.to_string()))
+                Err(Errors::from_couchdb(e, Errors::WriteError))
             }
         }
     }
 
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        // _id는 key_timestamp 형태이므로 key 필드로 조회 후 가장 최근 문서를 사용
+        let query = FindQuery::new(json!({ "key": key }));
+
+        let docs = self
+            .db
+            .find::<BenchmarkDoc>(&query)
+            .await
+            .map_err(|e| Errors::from_couchdb(e, Errors::ReadError))?;
+
+        Ok(docs
+            .rows
+            .into_iter()
+            .max_by_key(|doc| doc.timestamp)
+            .map(|doc| doc.value))
+    }
+
     fn worker_count(&self) -> usize {
         500
     }
@@ -1,23 +1,75 @@
 use reqwest::Client;
 use serde_json::json;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use tokio::time::{Duration, sleep};
 
-use super::{Database, Errors, Result};
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
+use crate::record::{FieldType, Record, Schema};
+
+/// 같은 REST API를 쓰는 Elasticsearch 계열 (OpenSearch는 Elasticsearch 7.10의 fork)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Flavor {
+    Elasticsearch,
+    OpenSearch,
+}
+
+impl Flavor {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Flavor::Elasticsearch => "Elasticsearch",
+            Flavor::OpenSearch => "OpenSearch",
+        }
+    }
+
+    // docker-compose.yml 기준 주소
+    fn default_url(&self) -> &'static str {
+        match self {
+            Flavor::Elasticsearch => "http://localhost:19200",
+            Flavor::OpenSearch => "http://localhost:19201",
+        }
+    }
+
+    // index를 만드는 record field의 mapping (json field의 type만 다름)
+    fn field_mapping(&self, kind: FieldType) -> serde_json::Value {
+        let kind = match kind {
+            FieldType::Int => "long",
+            FieldType::Float => "double",
+            FieldType::Bool => "boolean",
+            FieldType::Timestamp => "date",
+            FieldType::Text => "keyword",
+            FieldType::Json => match self {
+                Flavor::Elasticsearch => "flattened",
+                Flavor::OpenSearch => "flat_object",
+            },
+        };
+
+        json!({"type": kind})
+    }
+}
 
 #[derive(Debug)]
 pub struct ElasticsearchDB {
+    flavor: Flavor,
     client: Client,
     base_url: String,
     index_name: String,
+    // 같이 저장하는 value 조각 field 수 (--indexes의 최댓값, mapping은 앞쪽 일부에만 있음)
+    field_count: AtomicUsize,
 }
 
 impl ElasticsearchDB {
-    pub async fn new() -> Result<Arc<dyn Database + Send + Sync>> {
+    pub async fn new(
+        flavor: Flavor,
+        config: &BackendConfig,
+    ) -> Result<Arc<dyn Database + Send + Sync>> {
         let db = ElasticsearchDB {
+            flavor,
             client: Client::new(),
-            base_url: "http://localhost:19200".to_string(),
-            index_name: "benchmark_kv".to_string(),
+            base_url: config.http_url_or(flavor.default_url()),
+            index_name: config.table_or("benchmark_kv"),
+            field_count: AtomicUsize::new(0),
         };
 
         Ok(Arc::new(db))
@@ -32,9 +84,10 @@ impl ElasticsearchDB {
                 }
             }
         }
-        Err(Errors::ConnectionError(
-            "Failed to connect to Elasticsearch".into(),
-        ))
+        Err(Errors::ConnectionError(format!(
+            "Failed to connect to {}",
+            self.flavor.name()
+        )))
     }
 
     async fn check_health(&self) -> Result<()> {
@@ -44,7 +97,7 @@ impl ElasticsearchDB {
             .get(&url)
             .send()
             .await
-            .map_err(|e| Errors::ConnectionError(e.to_string()))?;
+            .map_err(|e| Errors::from_reqwest(e, Errors::ConnectionError))?;
 
         if response.status().is_success() {
             Ok(())
@@ -52,6 +105,119 @@ impl ElasticsearchDB {
             Err(Errors::ConnectionError("Health check failed".into()))
         }
     }
+
+    async fn data_node_count(&self) -> Result<u64> {
+        let url = format!("{}/_cluster/health", self.base_url);
+        let health: serde_json::Value = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ConnectionError))?
+            .json()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ConnectionError))?;
+
+        Ok(health["number_of_data_nodes"].as_u64().unwrap_or(0))
+    }
+
+    // value 조각은 value_<i> field로 같이 저장 (mapping이 없는 field는 _source에만 남고 index되지 않음)
+    fn document(&self, key: &str, value: &str) -> serde_json::Value {
+        let mut doc = json!({
+            "key": key,
+            "value": value
+        });
+
+        let field_count = self.field_count.load(Ordering::Relaxed);
+        for (i, slice) in super::index_slices(value, field_count)
+            .into_iter()
+            .enumerate()
+        {
+            doc[format!("value_{}", i)] = slice.into();
+        }
+
+        doc
+    }
+
+    async fn put_document(&self, key: &str, doc: &serde_json::Value) -> Result<()> {
+        let url = format!("{}/{}/_doc/{}", self.base_url, self.index_name, key);
+        let response = self
+            .client
+            .put(&url)
+            .json(doc)
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::WriteError))?;
+
+        if !response.status().is_success() {
+            return Err(Errors::from_status(
+                response.status(),
+                "Failed to insert document",
+                Errors::WriteError,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// 문서를 한 번의 _bulk 요청으로 index
+    async fn bulk_index(
+        &self,
+        docs: impl Iterator<Item = (&str, serde_json::Value)> + Send,
+    ) -> Result<()> {
+        // _bulk API는 action 줄과 문서 줄을 번갈아 쓰는 NDJSON
+        let mut body = String::new();
+        for (key, doc) in docs {
+            body.push_str(&json!({"index": {"_id": key}}).to_string());
+            body.push('\n');
+            body.push_str(&doc.to_string());
+            body.push('\n');
+        }
+
+        let url = format!("{}/{}/_bulk", self.base_url, self.index_name);
+        let response = self
+            .client
+            .post(&url)
+            .header("Content-Type", "application/x-ndjson")
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::WriteError))?;
+
+        if !response.status().is_success() {
+            return Err(Errors::from_status(
+                response.status(),
+                "Failed to bulk insert documents",
+                Errors::WriteError,
+            ));
+        }
+
+        // 일부 문서만 실패해도 200이 오므로 errors 필드를 확인
+        let result: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::WriteError))?;
+
+        // 실패한 첫 문서의 status로 종류를 나눔 (429면 thread pool이 가득 찬 것)
+        if result["errors"].as_bool().unwrap_or(false) {
+            let status = result["items"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|item| item["index"]["status"].as_u64())
+                .find(|status| *status >= 300)
+                .and_then(|status| reqwest::StatusCode::from_u16(status as u16).ok())
+                .unwrap_or(reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+
+            return Err(Errors::from_status(
+                status,
+                "Some documents failed in bulk insert",
+                Errors::WriteError,
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -60,9 +226,31 @@ impl Database for ElasticsearchDB {
         self.wait_for_connection().await
     }
 
-    async fn setup(&self) -> Result<()> {
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        // translog는 기본적으로 요청마다 fsync (request), async면 sync_interval(5초)마다
+        let (replicas, translog) = match durability {
+            None | Some(Durability::Fsync) => (0, "request"),
+            Some(Durability::OsBuffered) => (0, "async"),
+            Some(durability @ Durability::Memory) => {
+                return Err(
+                    durability.unsupported(self.flavor.name(), "every write goes to the translog")
+                );
+            }
+            Some(durability @ Durability::Replicated) => {
+                // replica shard는 primary와 다른 node에만 배치됨
+                if self.data_node_count().await? < 2 {
+                    return Err(durability
+                        .unsupported(self.flavor.name(), "the cluster has a single data node"));
+                }
+
+                (1, "request")
+            }
+        };
+
+        // mapping에 없는 field는 index하지 않음 (index할 field는 create_indexes에서 추가)
         let mapping = json!({
             "mappings": {
+                "dynamic": false,
                 "properties": {
                     "key": {"type": "keyword"},
                     "value": {"type": "text"}
@@ -70,8 +258,9 @@ impl Database for ElasticsearchDB {
             },
             "settings": {
                 "number_of_shards": 1,
-                "number_of_replicas": 0,
-                "refresh_interval": "30s"
+                "number_of_replicas": replicas,
+                "refresh_interval": "30s",
+                "translog": {"durability": translog}
             }
         });
 
@@ -87,7 +276,7 @@ impl Database for ElasticsearchDB {
             .json(&mapping)
             .send()
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_reqwest(e, Errors::WriteError))?;
 
         if !response.status().is_success() {
             return Err(Errors::WriteError("Failed to create index".into()));
@@ -96,26 +285,221 @@ impl Database for ElasticsearchDB {
         Ok(())
     }
 
+    async fn create_indexes(
+        &self,
+        count: usize,
+        field_count: usize,
+        schema: Option<&Schema>,
+    ) -> Result<()> {
+        // record document는 field에, key/value document는 value 조각 field에 mapping을 추가
+        let properties: serde_json::Map<String, serde_json::Value> = match schema {
+            Some(schema) => super::index_fields(schema, count)?
+                .iter()
+                .map(|field| (field.name.clone(), self.flavor.field_mapping(field.kind)))
+                .collect(),
+            None => (0..count)
+                .map(|i| (format!("value_{}", i), json!({"type": "keyword"})))
+                .collect(),
+        };
+        self.field_count.store(
+            if schema.is_none() { field_count } else { 0 },
+            Ordering::Relaxed,
+        );
+
+        if properties.is_empty() {
+            return Ok(());
+        }
+
+        let url = format!("{}/{}/_mapping", self.base_url, self.index_name);
+        let response = self
+            .client
+            .put(&url)
+            .json(&json!({ "properties": properties }))
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::WriteError))?;
+
+        if !response.status().is_success() {
+            return Err(Errors::WriteError("Failed to update mapping".into()));
+        }
+
+        Ok(())
+    }
+
     async fn write(&self, key: &str, value: &str) -> Result<()> {
-        let doc = json!({
-            "key": key,
-            "value": value
+        self.put_document(key, &self.document(key, value)).await
+    }
+
+    async fn write_batch(&self, entries: &[(String, String)]) -> Result<()> {
+        let docs = entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), self.document(key, value)));
+
+        self.bulk_index(docs).await
+    }
+
+    // record는 field를 그대로 document의 field로 저장 (index는 create_indexes에서 mapping한 field에만)
+    async fn write_record(&self, schema: &Schema, record: &Record) -> Result<()> {
+        let doc = serde_json::Value::Object(record.to_json(schema));
+        self.put_document(&record.key, &doc).await
+    }
+
+    async fn write_records(&self, schema: &Schema, records: &[Record]) -> Result<()> {
+        let docs = records.iter().map(|record| {
+            let doc = serde_json::Value::Object(record.to_json(schema));
+            (record.key.as_str(), doc)
         });
 
+        self.bulk_index(docs).await
+    }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
         let url = format!("{}/{}/_doc/{}", self.base_url, self.index_name, key);
         let response = self
             .client
-            .put(&url)
-            .json(&doc)
+            .get(&url)
             .send()
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_reqwest(e, Errors::ReadError))?;
+
+        if response.status().as_u16() == 404 {
+            return Ok(None);
+        }
 
         if !response.status().is_success() {
-            return Err(Errors::WriteError("Failed to insert document".into()));
+            return Err(Errors::from_status(
+                response.status(),
+                "Failed to get document",
+                Errors::ReadError,
+            ));
         }
 
-        Ok(())
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ReadError))?;
+
+        Ok(body["_source"]["value"]
+            .as_str()
+            .map(|value| value.to_string()))
+    }
+
+    async fn count(&self) -> Result<u64> {
+        // refresh 전에는 _count에 최근 문서가 반영되지 않음
+        let url = format!("{}/{}/_refresh", self.base_url, self.index_name);
+        self.client
+            .post(&url)
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ReadError))?;
+
+        let url = format!("{}/{}/_count", self.base_url, self.index_name);
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ReadError))?;
+
+        if !response.status().is_success() {
+            return Err(Errors::ReadError("Failed to count documents".into()));
+        }
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ReadError))?;
+
+        body["count"]
+            .as_u64()
+            .ok_or_else(|| Errors::ReadError("Invalid _count response".into()))
+    }
+
+    async fn storage_size(&self) -> Result<u64> {
+        // refresh해서 indexing buffer를 segment로 내린 뒤 replica까지 합친 store 크기
+        let url = format!("{}/{}/_refresh", self.base_url, self.index_name);
+        self.client
+            .post(&url)
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ReadError))?;
+
+        let url = format!("{}/{}/_stats/store", self.base_url, self.index_name);
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ReadError))?;
+
+        if !response.status().is_success() {
+            return Err(Errors::ReadError("Failed to get index stats".into()));
+        }
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ReadError))?;
+
+        body["_all"]["total"]["store"]["size_in_bytes"]
+            .as_u64()
+            .ok_or_else(|| Errors::ReadError("Invalid _stats response".into()))
+    }
+
+    async fn scan(&self, start_key: &str, count: usize) -> Result<Vec<(String, String)>> {
+        let query = json!({
+            "query": {
+                "range": {
+                    "key": {"gte": start_key}
+                }
+            },
+            "sort": [{"key": "asc"}],
+            "size": count
+        });
+
+        let url = format!("{}/{}/_search", self.base_url, self.index_name);
+        let response = self
+            .client
+            .post(&url)
+            .json(&query)
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ReadError))?;
+
+        if !response.status().is_success() {
+            return Err(Errors::from_status(
+                response.status(),
+                "Failed to search documents",
+                Errors::ReadError,
+            ));
+        }
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ReadError))?;
+
+        let rows = body["hits"]["hits"]
+            .as_array()
+            .map(|hits| {
+                hits.iter()
+                    .map(|hit| {
+                        (
+                            hit["_source"]["key"]
+                                .as_str()
+                                .unwrap_or_default()
+                                .to_string(),
+                            hit["_source"]["value"]
+                                .as_str()
+                                .unwrap_or_default()
+                                .to_string(),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(rows)
     }
 
     fn worker_count(&self) -> usize {
@@ -1,7 +1,13 @@
-use etcd_rs::{Client, ClientConfig, Endpoint, KeyRange, KeyValueOp, PutRequest, RangeRequest};
+use etcd_rs::{
+    Client, ClientConfig, Endpoint, KeyRange, KeyValueOp, PutRequest, RangeRequest, TxnRequest,
+};
 use std::sync::Arc;
 
-use super::{Database, Errors, Result};
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
+
+// etcd 기본 설정(--max-txn-ops)의 txn당 최대 operation 수
+const MAX_TXN_OPS: usize = 128;
 
 pub struct Etcd {
     client: Client,
@@ -14,10 +20,16 @@ impl std::fmt::Debug for Etcd {
 }
 
 impl Etcd {
-    pub async fn new() -> Result<Arc<dyn Database + Send + Sync>> {
-        let endpoint = Endpoint::new("http://127.0.0.1:2379");
-        let config = ClientConfig::new(vec![endpoint]);
-        let client = Client::connect(config)
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
+        config.reject_tls("etcd")?;
+
+        let endpoint = Endpoint::new(config.url_or("http://127.0.0.1:2379"));
+        let mut client_config = ClientConfig::new(vec![endpoint]);
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            client_config = client_config.auth(username, password);
+        }
+
+        let client = Client::connect(client_config)
             .await
             .map_err(|error| Errors::ConnectionError(error.to_string()))?;
 
@@ -38,7 +50,12 @@ impl Database for Etcd {
         Ok(())
     }
 
-    async fn setup(&self) -> Result<()> {
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        // WAL을 fsync하고 quorum에 복제한 뒤 응답함 (--unsafe-no-fsync는 서버 실행 옵션)
+        if let Some(durability @ (Durability::Memory | Durability::OsBuffered)) = durability {
+            return Err(durability.unsupported("etcd", "the WAL is always synced"));
+        }
+
         // etcd는 별도의 테이블 설정이 필요하지 않음
         // 필요시 기존 key들을 정리할 수 있지만 일반적으로는 필요 없음
         Ok(())
@@ -50,8 +67,55 @@ impl Database for Etcd {
         self.client
             .put(put_request)
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_etcd(e, Errors::WriteError))?;
+
+        Ok(())
+    }
+
+    async fn write_batch(&self, entries: &[(String, String)]) -> Result<()> {
+        // txn 하나에 최대 MAX_TXN_OPS개의 put을 담아서 전송
+        for chunk in entries.chunks(MAX_TXN_OPS) {
+            let txn = chunk.iter().fold(TxnRequest::new(), |txn, (key, value)| {
+                txn.and_then(PutRequest::new(key.as_str(), value.as_str()))
+            });
+
+            self.client
+                .txn(txn)
+                .await
+                .map_err(|e| Errors::from_etcd(e, Errors::WriteError))?;
+        }
 
         Ok(())
     }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let response = self
+            .client
+            .get(RangeRequest::new(KeyRange::key(key)))
+            .await
+            .map_err(|e| Errors::from_etcd(e, Errors::ReadError))?;
+
+        Ok(response
+            .kvs
+            .into_iter()
+            .next()
+            .map(|kv| String::from_utf8_lossy(&kv.value).into_owned()))
+    }
+
+    async fn scan(&self, start_key: &str, count: usize) -> Result<Vec<(String, String)>> {
+        // range_end가 "\0"이면 start_key 이상의 모든 key (etcd는 기본적으로 key 순으로 정렬)
+        let request = RangeRequest::new(KeyRange::range(start_key, vec![0])).limit(count as u64);
+
+        let response = self
+            .client
+            .get(request)
+            .await
+            .map_err(|e| Errors::from_etcd(e, Errors::ReadError))?;
+
+        Ok(response
+            .kvs
+            .iter()
+            .map(|kv| (kv.key_str().to_string(), kv.value_str().to_string()))
+            .collect())
+    }
 }
@@ -0,0 +1,434 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use tokio::sync::Semaphore;
+
+use super::{Database, Durability, ErrorKind, Errors, Result};
+use crate::config::BackendConfig;
+use crate::record::Schema;
+
+/// 응답 시간 분포 (모든 값은 ms)
+#[derive(Clone, Copy, Debug)]
+pub enum Latency {
+    Constant {
+        latency_ms: f64,
+    },
+    Normal {
+        mean_ms: f64,
+        stddev_ms: f64,
+    },
+    // median = exp(mu)
+    LogNormal {
+        median_ms: f64,
+        sigma: f64,
+    },
+    // slow_rate 비율의 요청만 slow_ms로 응답 (GC, cache miss 등)
+    Bimodal {
+        fast_ms: f64,
+        slow_ms: f64,
+        slow_rate: f64,
+    },
+}
+
+impl Latency {
+    fn sample<R: Rng>(&self, rng: &mut R) -> Duration {
+        let millis = match *self {
+            Latency::Constant { latency_ms } => latency_ms,
+            Latency::Normal { mean_ms, stddev_ms } => mean_ms + stddev_ms * standard_normal(rng),
+            Latency::LogNormal { median_ms, sigma } => {
+                median_ms * (sigma * standard_normal(rng)).exp()
+            }
+            Latency::Bimodal {
+                fast_ms,
+                slow_ms,
+                slow_rate,
+            } => {
+                if rng.random::<f64>() < slow_rate {
+                    slow_ms
+                } else {
+                    fast_ms
+                }
+            }
+        };
+
+        Duration::from_secs_f64(millis.max(0.0) / 1000.0)
+    }
+}
+
+// Box-Muller 변환
+fn standard_normal<R: Rng>(rng: &mut R) -> f64 {
+    let u1: f64 = 1.0 - rng.random::<f64>();
+    let u2: f64 = rng.random();
+
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// 주기적으로 모든 요청이 멈추는 구간 (compaction, checkpoint 흉내)
+#[derive(Clone, Copy, Debug)]
+pub struct Stall {
+    pub every: Duration,
+    pub duration: Duration,
+}
+
+/// simulated backend 설정. url의 query string으로 지정한다.
+///
+/// ```toml
+/// [fake]
+/// url = "fake://?latency=lognormal&latency_ms=5&sigma=0.8&error_rate=0.01&error_kind=throttled&stall_every_ms=10000&stall_ms=500&capacity=100"
+/// ```
+#[derive(Clone, Debug)]
+pub struct Profile {
+    pub latency: Latency,
+    // 요청이 실패할 확률 (0.0 ~ 1.0)
+    pub error_rate: f64,
+    // 실패한 요청이 반환하는 error 종류
+    pub error_kind: ErrorKind,
+    pub stall: Option<Stall>,
+    // 동시에 처리하는 최대 요청 수. 넘는 요청은 대기열에서 기다림
+    pub capacity: Option<usize>,
+    pub seed: Option<u64>,
+}
+
+impl Profile {
+    pub fn parse(url: &str) -> Result<Self> {
+        let url = reqwest::Url::parse(url)
+            .map_err(|e| Errors::ConnectionError(format!("Invalid url: {}", e)))?;
+
+        let mut params: BTreeMap<String, String> = url.query_pairs().into_owned().collect();
+
+        let latency_ms = number(&mut params, "latency_ms", 5.0)?;
+        let latency = match params.remove("latency").as_deref() {
+            None | Some("constant") => Latency::Constant { latency_ms },
+            Some("normal") => Latency::Normal {
+                mean_ms: latency_ms,
+                stddev_ms: number(&mut params, "stddev_ms", latency_ms / 4.0)?,
+            },
+            Some("lognormal") => Latency::LogNormal {
+                median_ms: latency_ms,
+                sigma: number(&mut params, "sigma", 0.5)?,
+            },
+            Some("bimodal") => Latency::Bimodal {
+                fast_ms: latency_ms,
+                slow_ms: number(&mut params, "slow_ms", latency_ms * 10.0)?,
+                slow_rate: number(&mut params, "slow_rate", 0.05)?.min(1.0),
+            },
+            Some(other) => {
+                return Err(Errors::ConnectionError(format!(
+                    "Unknown latency distribution: {}",
+                    other
+                )));
+            }
+        };
+
+        let error_rate = number(&mut params, "error_rate", 0.0)?.min(1.0);
+        let error_kind = match params.remove("error_kind").as_deref() {
+            Some("timeout") => ErrorKind::Timeout,
+            Some("connection_refused") => ErrorKind::ConnectionRefused,
+            Some("conflict") => ErrorKind::Conflict,
+            Some("throttled") => ErrorKind::Throttled,
+            None | Some("server_error") => ErrorKind::ServerError,
+            Some(other) => {
+                return Err(Errors::ConnectionError(format!(
+                    "Unknown error kind: {}",
+                    other
+                )));
+            }
+        };
+
+        let stall_every_ms = number(&mut params, "stall_every_ms", 0.0)?;
+        let stall_ms = number(&mut params, "stall_ms", 0.0)?;
+        // 주기가 1 ms보다 짧으면 Duration으로 바꿀 때 0이 될 수 있으므로 1 ms로 올림
+        let stall = (stall_every_ms > 0.0 && stall_ms > 0.0).then(|| {
+            let stall_every_ms = stall_every_ms.max(1.0);
+            Stall {
+                every: Duration::from_secs_f64(stall_every_ms / 1000.0),
+                duration: Duration::from_secs_f64(stall_ms.min(stall_every_ms) / 1000.0),
+            }
+        });
+
+        let capacity = match number(&mut params, "capacity", 0.0)? as usize {
+            0 => None,
+            capacity => Some(capacity),
+        };
+
+        let seed = match params.remove("seed") {
+            Some(value) => Some(
+                value
+                    .parse::<u64>()
+                    .map_err(|_| Errors::ConnectionError(format!("Invalid seed: {}", value)))?,
+            ),
+            None => None,
+        };
+
+        // 오타로 설정이 무시되지 않도록 모르는 값은 에러
+        if let Some(name) = params.keys().next() {
+            return Err(Errors::ConnectionError(format!(
+                "Unknown fake backend option: {}",
+                name
+            )));
+        }
+
+        Ok(Profile {
+            latency,
+            error_rate,
+            error_kind,
+            stall,
+            capacity,
+            seed,
+        })
+    }
+}
+
+// 0 이상의 숫자 option (없으면 default)
+fn number(params: &mut BTreeMap<String, String>, name: &str, default: f64) -> Result<f64> {
+    match params.remove(name) {
+        Some(value) => match value.parse::<f64>() {
+            Ok(number) if number.is_finite() && number >= 0.0 => Ok(number),
+            _ => Err(Errors::ConnectionError(format!(
+                "Invalid {}: {}",
+                name, value
+            ))),
+        },
+        None => Ok(default),
+    }
+}
+
+/// DB 없이 driver의 통계, retry, report를 검증하기 위한 simulated backend.
+/// 쓴 값은 메모리에 저장하므로 read/scan 결과도 실제 DB처럼 동작한다.
+pub struct FakeDB {
+    profile: Profile,
+    rng: Mutex<StdRng>,
+    capacity: Option<Semaphore>,
+    started: Instant,
+    data: Mutex<BTreeMap<String, String>>,
+}
+
+impl FakeDB {
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
+        let profile = Profile::parse(&config.url_or("fake://"))?;
+
+        Ok(Arc::new(FakeDB::with_profile(profile)))
+    }
+
+    pub fn with_profile(profile: Profile) -> Self {
+        let rng = match profile.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_rng(&mut rand::rng()),
+        };
+
+        FakeDB {
+            capacity: profile.capacity.map(Semaphore::new),
+            profile,
+            rng: Mutex::new(rng),
+            started: Instant::now(),
+            data: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    // 매 주기의 마지막 stall.duration 동안 멈춤. stall 구간이면 끝날 때까지 남은 시간
+    fn stall_remaining(&self) -> Option<Duration> {
+        let stall = self.profile.stall?;
+        let elapsed = self.started.elapsed().as_nanos() % stall.every.as_nanos();
+        let remaining = stall.every - Duration::from_nanos(elapsed as u64);
+
+        (remaining <= stall.duration).then_some(remaining)
+    }
+
+    /// 요청 하나를 처리하는 시간만큼 대기하고, error_rate 확률로 실패
+    async fn request(&self) -> Result<()> {
+        let _permit = match &self.capacity {
+            Some(semaphore) => Some(semaphore.acquire().await.unwrap()),
+            None => None,
+        };
+
+        if let Some(remaining) = self.stall_remaining() {
+            tokio::time::sleep(remaining).await;
+        }
+
+        let (latency, failed) = {
+            let mut rng = self.rng.lock().unwrap();
+            (
+                self.profile.latency.sample(&mut *rng),
+                rng.random::<f64>() < self.profile.error_rate,
+            )
+        };
+
+        tokio::time::sleep(latency).await;
+
+        if failed {
+            let message = "injected error".to_string();
+            return Err(match self.profile.error_kind {
+                ErrorKind::Timeout => Errors::Timeout(message),
+                ErrorKind::ConnectionRefused => Errors::ConnectionRefused(message),
+                ErrorKind::Conflict => Errors::Conflict(message),
+                ErrorKind::Throttled => Errors::Throttled(message),
+                _ => Errors::ServerError(message),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Database for FakeDB {
+    async fn ping(&self) -> Result<()> {
+        Ok(())
+    }
+
+    // simulated backend이므로 어떤 durability든 그대로 받음
+    async fn setup(&self, _durability: Option<Durability>) -> Result<()> {
+        self.data.lock().unwrap().clear();
+        Ok(())
+    }
+
+    // index 수는 simulated latency에 영향을 주지 않음
+    async fn create_indexes(
+        &self,
+        _count: usize,
+        _field_count: usize,
+        _schema: Option<&Schema>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn write(&self, key: &str, value: &str) -> Result<()> {
+        self.request().await?;
+
+        self.data
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), value.to_string());
+
+        Ok(())
+    }
+
+    async fn write_batch(&self, entries: &[(String, String)]) -> Result<()> {
+        // batch 전체가 한 요청으로 처리됨
+        self.request().await?;
+
+        self.data.lock().unwrap().extend(entries.iter().cloned());
+
+        Ok(())
+    }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        self.request().await?;
+
+        Ok(self.data.lock().unwrap().get(key).cloned())
+    }
+
+    async fn count(&self) -> Result<u64> {
+        Ok(self.data.lock().unwrap().len() as u64)
+    }
+
+    // 저장한 key와 value의 byte 수
+    async fn storage_size(&self) -> Result<u64> {
+        let data = self.data.lock().unwrap();
+        Ok(data
+            .iter()
+            .map(|(key, value)| (key.len() + value.len()) as u64)
+            .sum())
+    }
+
+    async fn scan(&self, start_key: &str, count: usize) -> Result<Vec<(String, String)>> {
+        self.request().await?;
+
+        Ok(self
+            .data
+            .lock()
+            .unwrap()
+            .range(start_key.to_string()..)
+            .take(count)
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_to_constant_latency_without_errors() {
+        let profile = Profile::parse("fake://").unwrap();
+
+        assert!(matches!(
+            profile.latency,
+            Latency::Constant { latency_ms } if latency_ms == 5.0
+        ));
+        assert_eq!(profile.error_rate, 0.0);
+        assert_eq!(profile.error_kind, ErrorKind::ServerError);
+        assert!(profile.stall.is_none());
+        assert!(profile.capacity.is_none());
+        assert!(profile.seed.is_none());
+    }
+
+    #[test]
+    fn parses_every_option() {
+        let profile = Profile::parse(
+            "fake://?latency=lognormal&latency_ms=5&sigma=0.8&error_rate=0.01&error_kind=throttled\
+             &stall_every_ms=10000&stall_ms=500&capacity=100&seed=7",
+        )
+        .unwrap();
+
+        assert!(matches!(
+            profile.latency,
+            Latency::LogNormal { median_ms, sigma } if median_ms == 5.0 && sigma == 0.8
+        ));
+        assert_eq!(profile.error_rate, 0.01);
+        assert_eq!(profile.error_kind, ErrorKind::Throttled);
+        let stall = profile.stall.unwrap();
+        assert_eq!(stall.every, Duration::from_secs(10));
+        assert_eq!(stall.duration, Duration::from_millis(500));
+        assert_eq!(profile.capacity, Some(100));
+        assert_eq!(profile.seed, Some(7));
+    }
+
+    #[test]
+    fn distribution_parameters_default_from_latency_ms() {
+        let profile = Profile::parse("fake://?latency=bimodal&latency_ms=2").unwrap();
+
+        assert!(matches!(
+            profile.latency,
+            Latency::Bimodal { fast_ms, slow_ms, slow_rate }
+                if fast_ms == 2.0 && slow_ms == 20.0 && slow_rate == 0.05
+        ));
+    }
+
+    #[test]
+    fn clamps_rates_and_stall_duration() {
+        let profile =
+            Profile::parse("fake://?error_rate=3&stall_every_ms=100&stall_ms=500").unwrap();
+
+        assert_eq!(profile.error_rate, 1.0);
+        assert_eq!(profile.stall.unwrap().duration, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn stall_period_is_at_least_one_millisecond() {
+        let profile = Profile::parse("fake://?stall_every_ms=0.0000001&stall_ms=5").unwrap();
+        let stall = profile.stall.unwrap();
+
+        assert_eq!(stall.every, Duration::from_millis(1));
+        assert_eq!(stall.duration, Duration::from_millis(1));
+    }
+
+    #[test]
+    fn rejects_invalid_options() {
+        for url in [
+            "fake://?latency=uniform",
+            "fake://?error_kind=fatal",
+            "fake://?latency_ms=-1",
+            "fake://?latency_ms=NaN",
+            "fake://?seed=abc",
+            "fake://?latancy_ms=5",
+        ] {
+            assert!(Profile::parse(url).is_err(), "{} was accepted", url);
+        }
+    }
+}
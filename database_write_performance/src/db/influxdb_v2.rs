@@ -4,27 +4,98 @@ use std::{
 };
 
 use futures::stream;
-use influxdb2::{Client, models::DataPoint};
+use influxdb2::{
+    Client, FromDataPoint,
+    models::{DataPoint, Query},
+};
+
+use crate::config::BackendConfig;
+use crate::db::{Database, Durability, Errors, Result};
+use crate::record::{Record, Schema, Value};
 
-use crate::db::{Database, Errors, Result};
+#[derive(Debug, Default, FromDataPoint)]
+struct ValueRow {
+    value: String,
+}
 
 #[derive(Clone)]
 pub struct InfluxDB {
     client: Client,
+    bucket: String,
+    measurement: String,
 }
 
 impl InfluxDB {
-    pub async fn new() -> Result<Arc<dyn Database + Send + Sync>> {
-        let host = "http://localhost:18086";
-        let org = "benchmark";
-        let token = "benchmark-token-1234567890abcdef";
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
+        let host = config.http_url_or("http://localhost:18086");
+        let org = config
+            .org
+            .clone()
+            .unwrap_or_else(|| "benchmark".to_string());
+        let token = config
+            .token
+            .clone()
+            .unwrap_or_else(|| "benchmark-token-1234567890abcdef".to_string());
 
         let client = Client::new(host, org, token);
 
-        let db = InfluxDB { client };
+        let db = InfluxDB {
+            client,
+            bucket: config.database_or("benchmark"),
+            measurement: config.table_or("benchmark"),
+        };
 
         Ok(Arc::new(db))
     }
+
+    // key는 write와 같이 field, tag field는 tag, 나머지는 type에 맞는 field
+    // 첫 번째 timestamp field가 있으면 point의 시각으로 쓰고, 없으면 timestamp 사용
+    fn record_point(&self, schema: &Schema, record: &Record, timestamp: i64) -> Result<DataPoint> {
+        let time_field = schema.time_field();
+        let timestamp = match time_field.map(|i| &record.values[i]) {
+            Some(Value::Timestamp(millis)) => millis * 1_000_000,
+            _ => timestamp,
+        };
+
+        let mut builder = DataPoint::builder(&self.measurement).field("key", record.key.as_str());
+
+        for (i, (field, value)) in schema.fields.iter().zip(&record.values).enumerate() {
+            if Some(i) == time_field {
+                continue;
+            }
+
+            builder = match value {
+                Value::Null => builder,
+                Value::Text(value) if field.tag => builder.tag(&field.name, value),
+                Value::Int(value) => builder.field(&field.name, *value),
+                Value::Float(value) => builder.field(&field.name, *value),
+                Value::Bool(value) => builder.field(&field.name, *value),
+                // 다른 timestamp field는 epoch milliseconds 정수
+                Value::Timestamp(millis) => builder.field(&field.name, *millis),
+                Value::Text(value) => builder.field(&field.name, value.as_str()),
+                Value::Json(value) => builder.field(&field.name, value.to_string()),
+            };
+        }
+
+        builder
+            .timestamp(timestamp)
+            .build()
+            .map_err(|e| Errors::WriteError(e.to_string()))
+    }
+
+    async fn write_points(&self, points: Vec<DataPoint>) -> Result<()> {
+        self.client
+            .write(&self.bucket, stream::iter(points))
+            .await
+            .map_err(|e| Errors::from_influxdb2(e, Errors::WriteError))
+    }
+}
+
+fn now_nanos() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as i64
 }
 
 #[async_trait::async_trait]
@@ -38,19 +109,29 @@ impl Database for InfluxDB {
         Ok(())
     }
 
-    async fn setup(&self) -> Result<()> {
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        // 쓰기마다 WAL을 fsync한 뒤 응답함 (storage-wal-fsync-delay는 서버 옵션)
+        match durability {
+            None | Some(Durability::Fsync) => {}
+            Some(durability @ (Durability::Memory | Durability::OsBuffered)) => {
+                return Err(
+                    durability.unsupported("InfluxDB 2", "the WAL fsync delay is a server option")
+                );
+            }
+            Some(durability @ Durability::Replicated) => {
+                return Err(durability.unsupported("InfluxDB 2", "OSS has no replication"));
+            }
+        }
+
         // InfluxDB는 스키마가 없으므로 특별한 setup이 필요 없음
         // bucket은 이미 docker-compose에서 초기화됨
         Ok(())
     }
 
     async fn write(&self, key: &str, value: &str) -> Result<()> {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_nanos() as i64;
+        let timestamp = now_nanos();
 
-        let data_point = DataPoint::builder("benchmark")
+        let data_point = DataPoint::builder(&self.measurement)
             .field("key", key)
             .field("value", value)
             .timestamp(timestamp)
@@ -59,14 +140,56 @@ impl Database for InfluxDB {
 
         match self
             .client
-            .write("benchmark", stream::iter(vec![data_point]))
+            .write(&self.bucket, stream::iter(vec![data_point]))
             .await
         {
             Ok(_) => Ok(()),
             Err(e) => {
                 eprintln!("InfluxDB write error: {:?}", e);
-                Err(Errors::WriteError(e.to_string()))
+                Err(Errors::from_influxdb2(e, Errors::WriteError))
             }
         }
     }
+
+    async fn write_record(&self, schema: &Schema, record: &Record) -> Result<()> {
+        let point = self.record_point(schema, record, now_nanos())?;
+        self.write_points(vec![point]).await
+    }
+
+    async fn write_records(&self, schema: &Schema, records: &[Record]) -> Result<()> {
+        // 같은 series의 point가 같은 시각으로 덮어써지지 않도록 1ns씩 증가
+        let timestamp = now_nanos();
+
+        let points = records
+            .iter()
+            .enumerate()
+            .map(|(i, record)| self.record_point(schema, record, timestamp + i as i64))
+            .collect::<Result<Vec<DataPoint>>>()?;
+
+        self.write_points(points).await
+    }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        // key, value가 모두 field로 저장되므로 pivot 후 key로 필터링
+        let flux = format!(
+            r#"from(bucket: "{}")
+                |> range(start: 0)
+                |> filter(fn: (r) => r._measurement == "{}")
+                |> pivot(rowKey: ["_time"], columnKey: ["_field"], valueColumn: "_value")
+                |> filter(fn: (r) => r.key == "{}")
+                |> sort(columns: ["_time"], desc: true)
+                |> limit(n: 1)"#,
+            self.bucket,
+            self.measurement,
+            key.replace('"', "\\\"")
+        );
+
+        let rows = self
+            .client
+            .query::<ValueRow>(Some(Query::new(flux)))
+            .await
+            .map_err(|e| Errors::from_influxdb2(e, Errors::ReadError))?;
+
+        Ok(rows.into_iter().next().map(|row| row.value))
+    }
 }
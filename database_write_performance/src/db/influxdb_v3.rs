@@ -0,0 +1,269 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use influxdb::{InfluxDbWriteable, Query, Timestamp};
+use reqwest::{Client, Method, RequestBuilder};
+use serde_json::json;
+
+use crate::config::BackendConfig;
+use crate::db::{Database, Durability, Errors, Result};
+use crate::record::{Record, Schema, Value};
+
+#[derive(Debug)]
+pub struct InfluxDBv3 {
+    client: Client,
+    base_url: String,
+    // --without-auth로 실행한 경우 None
+    token: Option<String>,
+    database: String,
+    measurement: String,
+    // memory: WAL이 object store에 flush되기 전에 응답 (no_sync)
+    no_sync: AtomicBool,
+}
+
+impl InfluxDBv3 {
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
+        let db = InfluxDBv3 {
+            client: Client::new(),
+            base_url: config.http_url_or("http://localhost:18181"),
+            token: config.token.clone(),
+            database: config.database_or("benchmark"),
+            measurement: config.table_or("benchmark"),
+            no_sync: AtomicBool::new(false),
+        };
+
+        Ok(Arc::new(db))
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let request = self
+            .client
+            .request(method, format!("{}{}", self.base_url, path));
+
+        match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    // key는 tag, value는 field로 저장 (tag로 조회하므로 read가 빠름)
+    fn line(&self, key: &str, value: &str, timestamp: u128) -> Result<String> {
+        let query = Timestamp::Nanoseconds(timestamp)
+            .into_query(self.measurement.as_str())
+            .add_tag("key", key)
+            .add_field("value", value)
+            .build()
+            .map_err(|e| Errors::WriteError(e.to_string()))?;
+
+        Ok(query.get())
+    }
+
+    // tag field와 key는 tag, 나머지는 type에 맞는 field로 저장
+    // 첫 번째 timestamp field가 있으면 point의 시각으로 쓰고, 없으면 timestamp 사용
+    fn record_line(&self, schema: &Schema, record: &Record, timestamp: u128) -> Result<String> {
+        let time_field = schema.time_field();
+        let timestamp = match time_field.map(|i| &record.values[i]) {
+            Some(Value::Timestamp(millis)) if *millis >= 0 => *millis as u128 * 1_000_000,
+            _ => timestamp,
+        };
+
+        let mut query = Timestamp::Nanoseconds(timestamp)
+            .into_query(self.measurement.as_str())
+            .add_tag("key", record.key.as_str());
+
+        for (i, (field, value)) in schema.fields.iter().zip(&record.values).enumerate() {
+            if Some(i) == time_field {
+                continue;
+            }
+
+            query = match value {
+                Value::Null => query,
+                Value::Text(value) if field.tag => query.add_tag(&field.name, value.as_str()),
+                Value::Int(value) => query.add_field(&field.name, *value),
+                Value::Float(value) => query.add_field(&field.name, *value),
+                Value::Bool(value) => query.add_field(&field.name, *value),
+                // 다른 timestamp field는 epoch milliseconds 정수
+                Value::Timestamp(millis) => query.add_field(&field.name, *millis),
+                Value::Text(value) => query.add_field(&field.name, value.as_str()),
+                Value::Json(value) => query.add_field(&field.name, value.to_string()),
+            };
+        }
+
+        let query = query
+            .build()
+            .map_err(|e| Errors::WriteError(e.to_string()))?;
+
+        Ok(query.get())
+    }
+
+    async fn write_lines(&self, body: String) -> Result<()> {
+        let response = self
+            .request(Method::POST, "/api/v3/write_lp")
+            .query(&[("db", self.database.as_str()), ("precision", "nanosecond")])
+            .query(&[("no_sync", self.no_sync.load(Ordering::Relaxed))])
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::WriteError))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let message = response.text().await.unwrap_or_default();
+            return Err(Errors::from_status(
+                status,
+                &format!("Failed to write line protocol: {}", message),
+                Errors::WriteError,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn now_nanos() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos()
+}
+
+#[async_trait::async_trait]
+impl Database for InfluxDBv3 {
+    async fn ping(&self) -> Result<()> {
+        let response = self
+            .request(Method::GET, "/health")
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ConnectionError))?;
+
+        if !response.status().is_success() {
+            return Err(Errors::ConnectionError(format!(
+                "InfluxDB health check failed: {}",
+                response.status()
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        // 기본은 WAL이 object store(file)에 flush된 뒤 응답
+        match durability {
+            None | Some(Durability::Fsync) => self.no_sync.store(false, Ordering::Relaxed),
+            Some(Durability::Memory) => self.no_sync.store(true, Ordering::Relaxed),
+            Some(durability @ Durability::OsBuffered) => {
+                return Err(durability
+                    .unsupported("InfluxDB 3", "the WAL flush is not configurable per write"));
+            }
+            Some(durability @ Durability::Replicated) => {
+                return Err(durability.unsupported("InfluxDB 3", "Core runs on a single node"));
+            }
+        }
+
+        // 기존 database 삭제 (없으면 404)
+        let response = self
+            .request(Method::DELETE, "/api/v3/configure/database")
+            .query(&[("db", self.database.as_str())])
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::WriteError))?;
+
+        if !response.status().is_success() && response.status().as_u16() != 404 {
+            return Err(Errors::WriteError(format!(
+                "Failed to drop database: {}",
+                response.status()
+            )));
+        }
+
+        // 새 database 생성
+        let response = self
+            .request(Method::POST, "/api/v3/configure/database")
+            .json(&json!({ "db": self.database }))
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::WriteError))?;
+
+        if !response.status().is_success() {
+            return Err(Errors::WriteError(format!(
+                "Failed to create database: {}",
+                response.status()
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn write(&self, key: &str, value: &str) -> Result<()> {
+        let line = self.line(key, value, now_nanos())?;
+        self.write_lines(line).await
+    }
+
+    async fn write_batch(&self, entries: &[(String, String)]) -> Result<()> {
+        // line protocol은 한 요청에 여러 줄을 보낼 수 있음
+        // 같은 key가 같은 timestamp로 덮어써지지 않도록 줄마다 1ns씩 증가
+        let timestamp = now_nanos();
+
+        let lines = entries
+            .iter()
+            .enumerate()
+            .map(|(i, (key, value))| self.line(key, value, timestamp + i as u128))
+            .collect::<Result<Vec<String>>>()?;
+
+        self.write_lines(lines.join("\n")).await
+    }
+
+    async fn write_record(&self, schema: &Schema, record: &Record) -> Result<()> {
+        let line = self.record_line(schema, record, now_nanos())?;
+        self.write_lines(line).await
+    }
+
+    async fn write_records(&self, schema: &Schema, records: &[Record]) -> Result<()> {
+        let timestamp = now_nanos();
+
+        let lines = records
+            .iter()
+            .enumerate()
+            .map(|(i, record)| self.record_line(schema, record, timestamp + i as u128))
+            .collect::<Result<Vec<String>>>()?;
+
+        self.write_lines(lines.join("\n")).await
+    }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let sql = format!(
+            "SELECT value FROM \"{}\" WHERE \"key\" = '{}' ORDER BY time DESC LIMIT 1",
+            self.measurement.replace('"', "\"\""),
+            key.replace('\'', "''")
+        );
+
+        let response = self
+            .request(Method::POST, "/api/v3/query_sql")
+            .json(&json!({ "db": self.database, "q": sql, "format": "json" }))
+            .send()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ReadError))?;
+
+        if !response.status().is_success() {
+            return Err(Errors::from_status(
+                response.status(),
+                "Failed to query",
+                Errors::ReadError,
+            ));
+        }
+
+        let rows: Vec<serde_json::Value> = response
+            .json()
+            .await
+            .map_err(|e| Errors::from_reqwest(e, Errors::ReadError))?;
+
+        Ok(rows
+            .first()
+            .and_then(|row| row["value"].as_str())
+            .map(|value| value.to_string()))
+    }
+}
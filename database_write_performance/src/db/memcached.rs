@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use memcache::Client;
+
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
+
+// memcache client는 blocking이므로 spawn_blocking에서 호출
+pub struct Memcached {
+    client: Client,
+    pool_size: u32,
+}
+
+impl Memcached {
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
+        config.reject_tls("memcached")?;
+
+        if let Some(persistence) = &config.persistence
+            && persistence != "none"
+        {
+            return Err(Errors::Unsupported(format!(
+                "memcached does not support persistence mode {}",
+                persistence
+            )));
+        }
+
+        let pool_size = config.pool_size_or(512);
+        let url = config.url_or("memcache://127.0.0.1:11211?tcp_nodelay=true");
+
+        let client = tokio::task::spawn_blocking(move || {
+            Client::builder()
+                .add_server(url.as_str())?
+                .with_max_pool_size(pool_size)
+                .build()
+        })
+        .await
+        .map_err(|e| Errors::ConnectionError(e.to_string()))?
+        .map_err(|e| Errors::ConnectionError(e.to_string()))?;
+
+        Ok(Arc::new(Memcached { client, pool_size }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Database for Memcached {
+    async fn ping(&self) -> Result<()> {
+        let client = self.client.clone();
+
+        tokio::task::spawn_blocking(move || client.version())
+            .await
+            .map_err(|e| Errors::ConnectionError(e.to_string()))?
+            .map_err(|e| Errors::ConnectionError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        if let Some(durability) = durability
+            && durability != Durability::Memory
+        {
+            return Err(durability.unsupported("memcached", "data is kept in memory only"));
+        }
+
+        // 기존 데이터 삭제
+        let client = self.client.clone();
+
+        tokio::task::spawn_blocking(move || client.flush())
+            .await
+            .map_err(|e| Errors::ConnectionError(e.to_string()))?
+            .map_err(|e| Errors::ConnectionError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn write(&self, key: &str, value: &str) -> Result<()> {
+        let client = self.client.clone();
+        let (key, value) = (key.to_string(), value.to_string());
+
+        tokio::task::spawn_blocking(move || client.set(&key, value.as_str(), 0))
+            .await
+            .map_err(|e| Errors::WriteError(e.to_string()))?
+            .map_err(|e| Errors::from_memcache(e, Errors::WriteError))?;
+
+        Ok(())
+    }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let client = self.client.clone();
+        let key = key.to_string();
+
+        tokio::task::spawn_blocking(move || client.get::<String>(&key))
+            .await
+            .map_err(|e| Errors::ReadError(e.to_string()))?
+            .map_err(|e| Errors::from_memcache(e, Errors::ReadError))
+    }
+
+    // connection pool 크기보다 많이 보내면 blocking thread가 pool을 기다리기만 함
+    fn worker_count(&self) -> usize {
+        self.pool_size as usize
+    }
+}
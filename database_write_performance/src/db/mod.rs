@@ -1,5 +1,11 @@
+// 각 DB의 new()는 Self 대신 Arc<dyn Database>를 반환함
+#![allow(clippy::new_ret_no_self)]
+
 use std::{fmt::Debug, sync::Arc};
 
+use crate::config::Config;
+use crate::record::{Field, Record, Schema};
+
 pub mod barus;
 pub mod barus_grpc;
 pub mod cassandra;
@@ -8,18 +14,22 @@ pub mod cockroachdb;
 pub mod couchdb;
 pub mod elasticsearch;
 pub mod etcd;
+pub mod fake;
 pub mod influxdb_v2;
+pub mod influxdb_v3;
 pub mod mariadb;
+pub mod memcached;
 pub mod mongodb;
 pub mod mysql;
 pub mod nats;
-pub mod opensearch;
 pub mod postgres;
+pub mod resp;
 pub mod rrdb;
 pub mod scylla;
 pub mod tidb;
 pub mod tikv;
 pub mod timescaledb;
+pub mod ydb;
 pub mod yugabytedb;
 
 #[async_trait::async_trait]
@@ -29,39 +39,233 @@ pub trait Database {
 
     // create table if not exists
     // re-create table if exists
-    async fn setup(&self) -> Result<()>;
+    // durability가 있으면 그 수준에 맞게 설정하고, 맞출 수 없으면 Unsupported (None이면 adapter 기본값)
+    async fn setup(&self, durability: Option<Durability>) -> Result<()>;
+
+    // 모든 phase가 끝나면 (실패, interrupt 포함) 호출: setup에서 바꾼 서버 전체 설정을 원래 값으로 되돌림
+    async fn restore_settings(&self) -> Result<()> {
+        Ok(())
+    }
 
     // write key, value
     async fn write(&self, key: &str, value: &str) -> Result<()>;
 
+    // write multiple key, value at once
+    // native batch API가 없는 DB는 write를 순서대로 호출
+    async fn write_batch(&self, entries: &[(String, String)]) -> Result<()> {
+        for (key, value) in entries {
+            self.write(key, value).await?;
+        }
+
+        Ok(())
+    }
+
+    // setup 다음에 호출: record의 field를 native column으로 저장하도록 table을 바꿈
+    // 기본은 field를 JSON object 문자열로 value에 저장하므로 바꿀 것이 없음
+    async fn setup_schema(&self, _schema: &Schema) -> Result<()> {
+        Ok(())
+    }
+
+    // setup(과 setup_schema) 다음에 호출: secondary index를 count개 만듦 (--indexes)
+    // schema가 있으면 record field에, 없으면 value의 조각(index_slices)에 만듦
+    // value 조각을 value_<i> field로 저장하는 backend는 count와 관계없이 항상 field_count개
+    // (--indexes의 최댓값)를 저장해서, index 수를 비교할 때 write 크기가 같게 함
+    async fn create_indexes(
+        &self,
+        count: usize,
+        _field_count: usize,
+        _schema: Option<&Schema>,
+    ) -> Result<()> {
+        if count == 0 {
+            return Ok(());
+        }
+
+        Err(Errors::Unsupported("secondary indexes".into()))
+    }
+
+    // write record (native column/document/tag가 없는 DB는 JSON 문자열로 write)
+    async fn write_record(&self, schema: &Schema, record: &Record) -> Result<()> {
+        let value = serde_json::Value::Object(record.to_json(schema)).to_string();
+        self.write(&record.key, &value).await
+    }
+
+    // write multiple records at once
+    async fn write_records(&self, schema: &Schema, records: &[Record]) -> Result<()> {
+        let entries: Vec<(String, String)> = records
+            .iter()
+            .map(|record| {
+                let value = serde_json::Value::Object(record.to_json(schema)).to_string();
+                (record.key.clone(), value)
+            })
+            .collect();
+
+        self.write_batch(&entries).await
+    }
+
+    // read value by key (없으면 None)
+    async fn read(&self, key: &str) -> Result<Option<String>>;
+
+    // start_key 이상인 key를 정렬 순서대로 최대 count개 읽음
+    async fn scan(&self, _start_key: &str, _count: usize) -> Result<Vec<(String, String)>> {
+        Err(Errors::Unsupported("scan".into()))
+    }
+
+    // backend가 보고하는 저장된 row(document, key) 수 (verify phase에서 사용)
+    async fn count(&self) -> Result<u64> {
+        Err(Errors::Unsupported("count".into()))
+    }
+
+    // backend가 보고하는 table(collection, index)의 disk 사용량 (data + index, --indexes에서 사용)
+    async fn storage_size(&self) -> Result<u64> {
+        Err(Errors::Unsupported("storage_size".into()))
+    }
+
     fn worker_count(&self) -> usize {
         10000
     }
 }
 
-pub async fn new_database(db_type: &str) -> Result<Arc<dyn Database + Send + Sync>> {
+/// 쓰기가 성공으로 응답하기 전에 보장되어야 하는 저장 수준 (--durability)
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, clap::ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Durability {
+    // 메모리에만 기록 (process가 죽으면 유실)
+    Memory,
+    // OS page cache까지 기록 (process는 죽어도 되지만 OS나 전원 장애에는 유실)
+    OsBuffered,
+    // 응답 전에 disk에 fsync
+    Fsync,
+    // 응답 전에 다른 node의 replica까지 기록
+    Replicated,
+}
+
+impl Durability {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Durability::Memory => "memory",
+            Durability::OsBuffered => "os-buffered",
+            Durability::Fsync => "fsync",
+            Durability::Replicated => "replicated",
+        }
+    }
+
+    /// backend가 이 수준을 맞출 수 없을 때 setup에서 반환하는 error
+    pub fn unsupported(&self, backend: &str, reason: &str) -> Errors {
+        Errors::Unsupported(format!(
+            "{} cannot provide durability {}: {}",
+            backend,
+            self.name(),
+            reason
+        ))
+    }
+}
+
+// secondary index 하나가 덮는 value 조각의 글자 수
+pub const INDEX_SLICE_CHARS: usize = 8;
+
+/// value를 INDEX_SLICE_CHARS글자씩 나눈 앞쪽 count개 조각 (value가 짧으면 빈 문자열)
+///
+/// SQL backend는 substr expression에 index를 만들고,
+/// expression index가 없는 backend는 조각을 value_<i> column(field)으로 같이 저장함
+pub fn index_slices(value: &str, count: usize) -> Vec<String> {
+    let mut chars = value.chars();
+
+    (0..count)
+        .map(|_| chars.by_ref().take(INDEX_SLICE_CHARS).collect())
+        .collect()
+}
+
+/// record의 앞쪽 count개 field (index를 만들 대상)
+pub fn index_fields(schema: &Schema, count: usize) -> Result<&[Field]> {
+    schema.fields.get(..count).ok_or_else(|| {
+        Errors::Unsupported(format!(
+            "{} secondary indexes on a schema with {} fields",
+            count,
+            schema.fields.len()
+        ))
+    })
+}
+
+// new_database에서 생성 가능한 DB 목록 (--list 출력용, 아래 match와 같은 순서로 유지)
+pub const DATABASE_TYPES: &[&str] = &[
+    "postgres",
+    "rrdb",
+    "mysql",
+    "mariadb",
+    "mongodb",
+    "scylla",
+    "cassandra",
+    "influxdb_v2",
+    "influxdb_v3",
+    "timescaledb",
+    "couchdb",
+    "yugabytedb",
+    "cockroachdb",
+    "clickhouse",
+    "elasticsearch",
+    "opensearch",
+    "etcd",
+    "nats",
+    "tidb",
+    "tikv",
+    "barus",
+    "barus_grpc",
+    "ydb",
+    "redis",
+    "valkey",
+    "keydb",
+    "dragonfly",
+    "kvrocks",
+    "memcached",
+];
+
+// 실제 DB 없이 harness를 검증하기 위한 simulated backend (--list에는 나오지만 --all에는 포함하지 않음)
+pub const SIMULATED_TYPES: &[&str] = &["fake"];
+
+/// config에서 db_type section(+ 환경변수 override)을 읽어 adapter 생성
+pub async fn new_database(
+    db_type: &str,
+    config: &Config,
+) -> Result<Arc<dyn Database + Send + Sync>> {
+    let config = &config.backend(db_type);
+
     match db_type {
-        "postgres" => postgres::PostgresDB::new().await,
-        "rrdb" => rrdb::Rrdb::new().await,
-        "mysql" => mysql::MySqlDB::new().await,
-        "mariadb" => mariadb::MariaDB::new().await,
-        "mongodb" => mongodb::MongoDB::new().await,
-        "scylla" => cassandra::ScyllaDB::new().await,
-        "cassandra" => scylla::CassandraDB::new().await,
-        "influxdb_v2" => influxdb_v2::InfluxDB::new().await,
-        "timescaledb" => timescaledb::TimescaleDB::new().await,
-        "couchdb" => couchdb::CouchDB::new().await,
-        "yugabytedb" => yugabytedb::YugabyteDB::new().await,
-        "cockroachdb" => cockroachdb::CockroachDB::new().await,
-        "clickhouse" => clickhouse::ClickHouse::new().await,
-        "elasticsearch" => elasticsearch::ElasticsearchDB::new().await,
-        "opensearch" => opensearch::OpenSearchDB::new().await,
-        "etcd" => etcd::Etcd::new().await,
-        "nats" => nats::NatsJetStream::new().await,
-        "tidb" => tidb::TiDB::new().await,
-        "tikv" => tikv::TiKV::new().await,
-        "barus" => barus::BarusDB::new().await,
-        "barus_grpc" => barus_grpc::BarusDBGrpc::new().await,
+        "postgres" => postgres::PostgresDB::new(config).await,
+        "rrdb" => rrdb::Rrdb::new(config).await,
+        "mysql" => mysql::MySqlDB::new(config).await,
+        "mariadb" => mariadb::MariaDB::new(config).await,
+        "mongodb" => mongodb::MongoDB::new(config).await,
+        "scylla" => cassandra::ScyllaDB::new(config).await,
+        "cassandra" => scylla::CassandraDB::new(config).await,
+        "influxdb_v2" => influxdb_v2::InfluxDB::new(config).await,
+        "influxdb_v3" => influxdb_v3::InfluxDBv3::new(config).await,
+        "timescaledb" => timescaledb::TimescaleDB::new(config).await,
+        "couchdb" => couchdb::CouchDB::new(config).await,
+        "yugabytedb" => yugabytedb::YugabyteDB::new(config).await,
+        "cockroachdb" => cockroachdb::CockroachDB::new(config).await,
+        "clickhouse" => clickhouse::ClickHouse::new(config).await,
+        "elasticsearch" => {
+            elasticsearch::ElasticsearchDB::new(elasticsearch::Flavor::Elasticsearch, config).await
+        }
+        "opensearch" => {
+            elasticsearch::ElasticsearchDB::new(elasticsearch::Flavor::OpenSearch, config).await
+        }
+        "etcd" => etcd::Etcd::new(config).await,
+        "nats" => nats::NatsJetStream::new(config).await,
+        "tidb" => tidb::TiDB::new(config).await,
+        "tikv" => tikv::TiKV::new(config).await,
+        "barus" => barus::BarusDB::new(config).await,
+        "barus_grpc" => barus_grpc::BarusDBGrpc::new(config).await,
+        "ydb" => ydb::Ydb::new(config).await,
+        "redis" => resp::RespDB::new(resp::Flavor::Redis, config).await,
+        "valkey" => resp::RespDB::new(resp::Flavor::Valkey, config).await,
+        "keydb" => resp::RespDB::new(resp::Flavor::KeyDB, config).await,
+        "dragonfly" => resp::RespDB::new(resp::Flavor::Dragonfly, config).await,
+        "kvrocks" => resp::RespDB::new(resp::Flavor::Kvrocks, config).await,
+        "memcached" => memcached::Memcached::new(config).await,
+        "fake" => fake::FakeDB::new(config).await,
         _ => Err(Errors::ConnectionError("Unknown database type".into())),
     }
 }
@@ -69,7 +273,14 @@ pub async fn new_database(db_type: &str) -> Result<Arc<dyn Database + Send + Syn
 pub enum Errors {
     ConnectionError(String),
     WriteError(String),
-    ReadError,
+    ReadError(String),
+    Unsupported(String),
+    // client나 server가 원인을 알려준 경우 (retry 정책과 report에서 종류별로 구분)
+    Timeout(String),
+    ConnectionRefused(String),
+    Conflict(String),
+    Throttled(String),
+    ServerError(String),
 }
 
 impl Debug for Errors {
@@ -77,34 +288,490 @@ impl Debug for Errors {
         match self {
             Errors::ConnectionError(msg) => write!(f, "ConnectionError: {}", msg),
             Errors::WriteError(msg) => write!(f, "WriteError: {}", msg),
-            Errors::ReadError => write!(f, "ReadError"),
+            Errors::ReadError(msg) => write!(f, "ReadError: {}", msg),
+            Errors::Unsupported(msg) => write!(f, "Unsupported: {}", msg),
+            Errors::Timeout(msg) => write!(f, "Timeout: {}", msg),
+            Errors::ConnectionRefused(msg) => write!(f, "ConnectionRefused: {}", msg),
+            Errors::Conflict(msg) => write!(f, "Conflict: {}", msg),
+            Errors::Throttled(msg) => write!(f, "Throttled: {}", msg),
+            Errors::ServerError(msg) => write!(f, "ServerError: {}", msg),
         }
     }
 }
 
 pub type Result<T> = std::result::Result<T, Errors>;
 
-#[derive(Clone, Debug)]
-pub struct FakeDB {}
+/// report에서 실패를 나누는 error 종류
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    Timeout,
+    ConnectionRefused,
+    Conflict,
+    Throttled,
+    ServerError,
+    Unsupported,
+    // 분류할 수 없는 error
+    Other,
+}
 
-impl FakeDB {
-    pub fn new() -> Arc<dyn Database + Send + Sync> {
-        Arc::new(FakeDB {})
+impl ErrorKind {
+    pub const ALL: [ErrorKind; 7] = [
+        ErrorKind::Timeout,
+        ErrorKind::ConnectionRefused,
+        ErrorKind::Conflict,
+        ErrorKind::Throttled,
+        ErrorKind::ServerError,
+        ErrorKind::Unsupported,
+        ErrorKind::Other,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ErrorKind::Timeout => "timeout",
+            ErrorKind::ConnectionRefused => "connection refused",
+            ErrorKind::Conflict => "conflict",
+            ErrorKind::Throttled => "throttled",
+            ErrorKind::ServerError => "server error",
+            ErrorKind::Unsupported => "unsupported",
+            ErrorKind::Other => "other",
+        }
+    }
+
+    // adapter가 분류하지 않은 error는 message로 추정
+    fn from_message(message: &str) -> Self {
+        let message = message.to_lowercase();
+        let contains = |patterns: &[&str]| patterns.iter().any(|p| message.contains(p));
+
+        if contains(&["timed out", "timeout", "deadline exceeded"]) {
+            ErrorKind::Timeout
+        } else if contains(&["connection refused"]) {
+            ErrorKind::ConnectionRefused
+        } else if contains(&[
+            "conflict",
+            "deadlock",
+            "could not serialize",
+            "serialization failure",
+            "restart transaction",
+            "duplicate key",
+        ]) {
+            ErrorKind::Conflict
+        } else if contains(&[
+            "too many",
+            "throttl",
+            "rate limit",
+            "overloaded",
+            "resource exhausted",
+            "resource_exhausted",
+            "server is busy",
+        ]) {
+            ErrorKind::Throttled
+        } else if contains(&[
+            "internal server error",
+            "service unavailable",
+            "unavailable",
+        ]) {
+            ErrorKind::ServerError
+        } else {
+            ErrorKind::Other
+        }
     }
 }
 
-#[async_trait::async_trait]
-impl Database for FakeDB {
-    async fn ping(&self) -> Result<()> {
-        Ok(())
+impl Errors {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Errors::Timeout(_) => ErrorKind::Timeout,
+            Errors::ConnectionRefused(_) => ErrorKind::ConnectionRefused,
+            Errors::Conflict(_) => ErrorKind::Conflict,
+            Errors::Throttled(_) => ErrorKind::Throttled,
+            Errors::ServerError(_) => ErrorKind::ServerError,
+            Errors::Unsupported(_) => ErrorKind::Unsupported,
+            Errors::ConnectionError(msg) | Errors::WriteError(msg) | Errors::ReadError(msg) => {
+                ErrorKind::from_message(msg)
+            }
+        }
     }
 
-    async fn setup(&self) -> Result<()> {
-        Ok(())
+    /// sqlx error를 종류별로 나누고, 나눌 수 없으면 fallback(WriteError, ReadError 등)으로 감쌈
+    pub fn from_sqlx(error: sqlx::Error, fallback: fn(String) -> Errors) -> Errors {
+        let message = error.to_string();
+
+        match &error {
+            sqlx::Error::PoolTimedOut => Errors::Timeout(message),
+            sqlx::Error::Io(io) => Errors::from_io(io, message, fallback),
+            // SQLSTATE class 40 (serialization failure, deadlock), 23505 (unique violation),
+            // 53 (too many connections 등 자원 부족), 57014 (statement timeout)
+            sqlx::Error::Database(database) => match database.code().as_deref() {
+                Some(code) if code.starts_with("40") || code == "23505" => {
+                    Errors::Conflict(message)
+                }
+                Some(code) if code.starts_with("53") => Errors::Throttled(message),
+                Some("57014") => Errors::Timeout(message),
+                _ => match ErrorKind::from_message(&message) {
+                    ErrorKind::Other => Errors::ServerError(message),
+                    _ => fallback(message),
+                },
+            },
+            _ => fallback(message),
+        }
     }
 
-    async fn write(&self, _key: &str, _value: &str) -> Result<()> {
-        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
-        Ok(())
+    // 연결 단계의 io error
+    fn from_io(error: &std::io::Error, message: String, fallback: fn(String) -> Errors) -> Errors {
+        match error.kind() {
+            std::io::ErrorKind::ConnectionRefused => Errors::ConnectionRefused(message),
+            std::io::ErrorKind::TimedOut => Errors::Timeout(message),
+            _ => fallback(message),
+        }
+    }
+
+    /// gRPC status code로 종류를 나눔
+    ///
+    /// client crate마다 tonic version이 달라서 tonic::Code 대신 code 값(status.code() as i32)을 받음
+    pub fn from_grpc(code: i32, message: String, fallback: fn(String) -> Errors) -> Errors {
+        match code {
+            // DEADLINE_EXCEEDED
+            4 => Errors::Timeout(message),
+            // ALREADY_EXISTS, ABORTED
+            6 | 10 => Errors::Conflict(message),
+            // RESOURCE_EXHAUSTED
+            8 => Errors::Throttled(message),
+            // UNIMPLEMENTED
+            12 => Errors::Unsupported(message),
+            // UNAVAILABLE (tonic은 접속 실패도 UNAVAILABLE로 보고함)
+            14 if message.to_lowercase().contains("connection refused") => {
+                Errors::ConnectionRefused(message)
+            }
+            // INTERNAL, UNAVAILABLE, DATA_LOSS
+            13..=15 => Errors::ServerError(message),
+            _ => fallback(message),
+        }
+    }
+
+    /// HTTP API의 실패 응답 (status code로 종류를 나눔)
+    pub fn from_status(
+        status: reqwest::StatusCode,
+        message: &str,
+        fallback: fn(String) -> Errors,
+    ) -> Errors {
+        let message = format!("{} ({})", message, status);
+
+        match status.as_u16() {
+            408 | 504 => Errors::Timeout(message),
+            409 => Errors::Conflict(message),
+            429 => Errors::Throttled(message),
+            500..=599 => Errors::ServerError(message),
+            _ => fallback(message),
+        }
+    }
+
+    /// 요청을 보내지 못한 reqwest error
+    pub fn from_reqwest(error: reqwest::Error, fallback: fn(String) -> Errors) -> Errors {
+        let message = error.to_string();
+
+        if error.is_timeout() {
+            Errors::Timeout(message)
+        } else if error.is_connect() {
+            Errors::ConnectionRefused(message)
+        } else if let Some(status) = error.status() {
+            Errors::from_status(status, &message, fallback)
+        } else {
+            fallback(message)
+        }
+    }
+
+    pub fn from_redis(error: redis::RedisError, fallback: fn(String) -> Errors) -> Errors {
+        let message = error.to_string();
+
+        if error.is_timeout() {
+            Errors::Timeout(message)
+        } else if error.is_connection_refusal() {
+            Errors::ConnectionRefused(message)
+        } else {
+            match error.kind() {
+                redis::ErrorKind::BusyLoadingError | redis::ErrorKind::TryAgain => {
+                    Errors::Throttled(message)
+                }
+                redis::ErrorKind::ExecAbortError => Errors::Conflict(message),
+                _ => fallback(message),
+            }
+        }
+    }
+
+    pub fn from_mongodb(error: ::mongodb::error::Error, fallback: fn(String) -> Errors) -> Errors {
+        use ::mongodb::error::{ErrorKind as MongoErrorKind, WriteFailure};
+
+        let message = error.to_string();
+
+        // 서버가 보낸 error code (bulk write는 첫 번째 write error, 없으면 write concern error)
+        let code = match error.kind.as_ref() {
+            MongoErrorKind::Command(command) => Some(command.code),
+            MongoErrorKind::Write(WriteFailure::WriteError(write)) => Some(write.code),
+            MongoErrorKind::Write(WriteFailure::WriteConcernError(write_concern)) => {
+                Some(write_concern.code)
+            }
+            MongoErrorKind::BulkWrite(bulk) => bulk
+                .write_errors
+                .values()
+                .map(|write| write.code)
+                .chain(bulk.write_concern_errors.iter().map(|wc| wc.code))
+                .next(),
+            MongoErrorKind::InsertMany(insert) => insert
+                .write_errors
+                .iter()
+                .flatten()
+                .map(|write| write.code)
+                .chain(insert.write_concern_error.iter().map(|wc| wc.code))
+                .next(),
+            MongoErrorKind::Io(io) => return Errors::from_io(io, message, fallback),
+            // 응답하는 server를 찾지 못함 (serverSelectionTimeoutMS)
+            MongoErrorKind::ServerSelection { .. } => {
+                return match ErrorKind::from_message(&message) {
+                    ErrorKind::ConnectionRefused => Errors::ConnectionRefused(message),
+                    _ => Errors::Timeout(message),
+                };
+            }
+            MongoErrorKind::ConnectionPoolCleared { .. } => return Errors::ServerError(message),
+            _ => None,
+        };
+
+        match code {
+            // MaxTimeMSExpired, WriteConcernFailed (wtimeout), NetworkTimeout, ExceededTimeLimit
+            Some(50 | 64 | 89 | 262) => Errors::Timeout(message),
+            // WriteConflict, DuplicateKey
+            Some(112 | 11000) => Errors::Conflict(message),
+            // IngressRequestRateLimitExceeded, Cosmos DB request rate is large
+            Some(462 | 16500) => Errors::Throttled(message),
+            // replica set 상태 변경 (ShutdownInProgress, PrimarySteppedDown, NotWritablePrimary,
+            // InterruptedAtShutdown, InterruptedDueToReplStateChange, NotPrimaryNoSecondaryOk,
+            // NotPrimaryOrSecondary)
+            Some(91 | 189 | 10107 | 11600 | 11602 | 13435 | 13436) => Errors::ServerError(message),
+            _ => fallback(message),
+        }
+    }
+
+    pub fn from_scylla(
+        error: ::scylla::transport::errors::QueryError,
+        fallback: fn(String) -> Errors,
+    ) -> Errors {
+        use ::scylla::transport::errors::{DbError, QueryError};
+
+        let message = error.to_string();
+
+        match &error {
+            QueryError::DbError(db_error, _) => match db_error {
+                DbError::ReadTimeout { .. } | DbError::WriteTimeout { .. } => {
+                    Errors::Timeout(message)
+                }
+                DbError::Overloaded | DbError::RateLimitReached { .. } => {
+                    Errors::Throttled(message)
+                }
+                DbError::Unavailable { .. }
+                | DbError::IsBootstrapping
+                | DbError::ReadFailure { .. }
+                | DbError::WriteFailure { .. }
+                | DbError::ServerError => Errors::ServerError(message),
+                _ => fallback(message),
+            },
+            QueryError::TimeoutError | QueryError::RequestTimeout(_) => Errors::Timeout(message),
+            // connection의 stream id를 모두 사용 중 (client 쪽 in-flight 한도)
+            QueryError::UnableToAllocStreamId => Errors::Throttled(message),
+            _ => fallback(message),
+        }
+    }
+
+    pub fn from_etcd(error: etcd_rs::Error, fallback: fn(String) -> Errors) -> Errors {
+        use etcd_rs::Error;
+
+        // etcd_rs::Error는 원인을 message에 넣지 않음
+        match &error {
+            Error::Response(status) => Errors::from_grpc(
+                status.code() as i32,
+                format!("{}: {}", error, status),
+                fallback,
+            ),
+            Error::IOError(io) => Errors::from_io(io, format!("{}: {}", error, io), fallback),
+            Error::Transport(transport) => fallback(format!("{}: {:?}", error, transport)),
+            _ => fallback(error.to_string()),
+        }
+    }
+
+    pub fn from_clickhouse(
+        error: ::clickhouse::error::Error,
+        fallback: fn(String) -> Errors,
+    ) -> Errors {
+        use ::clickhouse::error::Error;
+
+        let message = error.to_string();
+
+        match &error {
+            Error::TimedOut => Errors::Timeout(message),
+            // server exception: "Code: 252. DB::Exception: ..."
+            Error::BadResponse(reason) => {
+                let code = reason
+                    .strip_prefix("Code: ")
+                    .and_then(|rest| rest.split('.').next())
+                    .and_then(|code| code.parse::<u32>().ok());
+
+                match code {
+                    // TIMEOUT_EXCEEDED, SOCKET_TIMEOUT
+                    Some(159 | 209) => Errors::Timeout(message),
+                    // TOO_MANY_SIMULTANEOUS_QUERIES, TOO_MANY_PARTS
+                    Some(202 | 252) => Errors::Throttled(message),
+                    // MEMORY_LIMIT_EXCEEDED
+                    Some(241) => Errors::ServerError(message),
+                    _ => fallback(message),
+                }
+            }
+            _ => fallback(message),
+        }
+    }
+
+    /// JetStream publish ack error (kv put은 원인을 source로 감쌈)
+    pub fn from_nats(
+        error: &(dyn std::error::Error + 'static),
+        fallback: fn(String) -> Errors,
+    ) -> Errors {
+        use async_nats::jetstream::context::{PublishError, PublishErrorKind};
+        use async_nats::jetstream::kv::{EntryError, EntryErrorKind};
+
+        let message = error.to_string();
+
+        let mut source = Some(error);
+        while let Some(error) = source {
+            if let Some(publish) = error.downcast_ref::<PublishError>() {
+                match publish.kind() {
+                    PublishErrorKind::TimedOut => return Errors::Timeout(message),
+                    PublishErrorKind::WrongLastMessageId | PublishErrorKind::WrongLastSequence => {
+                        return Errors::Conflict(message);
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(entry) = error.downcast_ref::<EntryError>()
+                && entry.kind() == EntryErrorKind::TimedOut
+            {
+                return Errors::Timeout(message);
+            }
+
+            source = error.source();
+        }
+
+        fallback(message)
+    }
+
+    pub fn from_couchdb(
+        error: couch_rs::error::CouchError,
+        fallback: fn(String) -> Errors,
+    ) -> Errors {
+        match &error {
+            couch_rs::error::CouchError::OperationFailed(details) => {
+                Errors::from_status(details.status, &details.message, fallback)
+            }
+            _ => fallback(error.to_string()),
+        }
+    }
+
+    pub fn from_influxdb2(
+        error: influxdb2::RequestError,
+        fallback: fn(String) -> Errors,
+    ) -> Errors {
+        use influxdb2::RequestError;
+
+        let message = error.to_string();
+
+        // influxdb2는 reqwest 0.11을 쓰므로 status code 값으로 변환
+        match &error {
+            RequestError::Http { status, text } => {
+                match reqwest::StatusCode::from_u16(status.as_u16()) {
+                    Ok(status) => Errors::from_status(status, text, fallback),
+                    Err(_) => fallback(message),
+                }
+            }
+            RequestError::ReqwestProcessing { source } if source.is_timeout() => {
+                Errors::Timeout(message)
+            }
+            RequestError::ReqwestProcessing { source } if source.is_connect() => {
+                Errors::ConnectionRefused(message)
+            }
+            _ => fallback(message),
+        }
+    }
+
+    pub fn from_ydb(error: ::ydb::YdbError, fallback: fn(String) -> Errors) -> Errors {
+        use ::ydb::YdbError;
+
+        let message = error.to_string();
+
+        match &error {
+            YdbError::DeadlineExceeded => Errors::Timeout(message),
+            YdbError::TransportGRPCStatus(status) => {
+                Errors::from_grpc(status.code() as i32, message, fallback)
+            }
+            // Ydb.StatusIds.StatusCode
+            YdbError::YdbStatusError(status) => match status.operation_status {
+                // ABORTED, ALREADY_EXISTS
+                400040 | 400130 => Errors::Conflict(message),
+                // OVERLOADED, SESSION_BUSY
+                400060 | 400190 => Errors::Throttled(message),
+                // TIMEOUT
+                400090 => Errors::Timeout(message),
+                // INTERNAL_ERROR, UNAVAILABLE
+                400030 | 400050 => Errors::ServerError(message),
+                _ => fallback(message),
+            },
+            _ => fallback(message),
+        }
+    }
+
+    pub fn from_memcache(error: memcache::MemcacheError, fallback: fn(String) -> Errors) -> Errors {
+        use memcache::{CommandError, MemcacheError};
+
+        let message = error.to_string();
+
+        match &error {
+            MemcacheError::IOError(io) => Errors::from_io(io, message, fallback),
+            // r2d2 pool에서 connection을 기다리다 timeout
+            MemcacheError::PoolError(_) => Errors::Timeout(message),
+            MemcacheError::CommandError(CommandError::KeyExists) => Errors::Conflict(message),
+            MemcacheError::ServerError(_) => Errors::ServerError(message),
+            _ => fallback(message),
+        }
+    }
+
+    pub fn from_tikv(error: tikv_client::Error, fallback: fn(String) -> Errors) -> Errors {
+        use tikv_client::Error;
+
+        let message = error.to_string();
+
+        match error {
+            Error::GrpcAPI(status) => Errors::from_grpc(status.code() as i32, message, fallback),
+            Error::Io(io) => Errors::from_io(&io, message, fallback),
+            Error::RegionError(region) if region.server_is_busy.is_some() => {
+                Errors::Throttled(message)
+            }
+            Error::RegionError(_) => Errors::ServerError(message),
+            Error::KeyError(key)
+                if key.conflict.is_some()
+                    || key.deadlock.is_some()
+                    || key.already_exist.is_some() =>
+            {
+                Errors::Conflict(message)
+            }
+            // 여러 error를 감싼 경우 첫 번째 error로 나눔
+            Error::UndeterminedError(inner) | Error::PessimisticLockError { inner, .. } => {
+                Errors::from_tikv(*inner, fallback)
+            }
+            Error::ExtractedErrors(errors) | Error::MultipleKeyErrors(errors) => {
+                match errors.into_iter().next() {
+                    Some(inner) => Errors::from_tikv(inner, fallback),
+                    None => fallback(message),
+                }
+            }
+            _ => fallback(message),
+        }
     }
 }
@@ -1,7 +1,8 @@
 use async_nats::jetstream::{self, kv};
 use std::sync::Arc;
 
-use super::{Database, Errors, Result};
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
 
 #[derive(Debug)]
 pub struct NatsJetStream {
@@ -9,19 +10,29 @@ pub struct NatsJetStream {
 }
 
 impl NatsJetStream {
-    pub async fn new() -> Result<Arc<dyn Database + Send + Sync>> {
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
         // NATS 클라이언트 연결
-        let client = async_nats::connect("nats://127.0.0.1:4222")
+        let mut options = async_nats::ConnectOptions::new().require_tls(config.tls());
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            options = options.user_and_password(username.clone(), password.clone());
+        } else if let Some(token) = &config.token {
+            options = options.token(token.clone());
+        }
+
+        let client = options
+            .connect(config.url_or("nats://127.0.0.1:4222"))
             .await
             .map_err(|error| Errors::ConnectionError(error.to_string()))?;
 
         // JetStream 컨텍스트 생성
         let jetstream = jetstream::new(client);
 
+        let bucket = config.database_or("benchmark");
+
         // Key-Value 스토어 생성 또는 연결
         let kv_store = match jetstream
             .create_key_value(jetstream::kv::Config {
-                bucket: "benchmark".to_string(),
+                bucket: bucket.clone(),
                 description: "Benchmark Key-Value Store".to_string(),
                 max_value_size: 1024 * 1024, // 1MB max value size
                 storage: jetstream::stream::StorageType::File,
@@ -34,7 +45,7 @@ impl NatsJetStream {
             Err(_) => {
                 // 이미 존재하는 경우 기존 스토어에 연결
                 jetstream
-                    .get_key_value("benchmark")
+                    .get_key_value(&bucket)
                     .await
                     .map_err(|error| Errors::ConnectionError(error.to_string()))?
             }
@@ -61,7 +72,22 @@ impl Database for NatsJetStream {
         Ok(())
     }
 
-    async fn setup(&self) -> Result<()> {
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        // bucket은 replica 1개의 file storage이고, fsync 주기는 서버의 sync_interval (기본 2분)
+        match durability {
+            None | Some(Durability::OsBuffered) => {}
+            Some(Durability::Memory) => {
+                return Err(Durability::Memory.unsupported("NATS", "the bucket uses file storage"));
+            }
+            Some(Durability::Fsync) => {
+                return Err(Durability::Fsync
+                    .unsupported("NATS", "sync_interval: always is a server option"));
+            }
+            Some(Durability::Replicated) => {
+                return Err(Durability::Replicated.unsupported("NATS", "the bucket has 1 replica"));
+            }
+        }
+
         // NATS JetStream KV는 별도의 테이블 설정이 필요하지 않음
         // 필요시 기존 키들을 정리할 수 있지만 일반적으로는 필요 없음
         Ok(())
@@ -72,8 +98,18 @@ impl Database for NatsJetStream {
         self.kv_store
             .put(key, value_bytes.into())
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_nats(&e, Errors::WriteError))?;
 
         Ok(())
     }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let value = self
+            .kv_store
+            .get(key)
+            .await
+            .map_err(|e| Errors::from_nats(&e, Errors::ReadError))?;
+
+        Ok(value.map(|bytes| String::from_utf8_lossy(&bytes).into_owned()))
+    }
 }
@@ -0,0 +1,349 @@
+use std::sync::{
+    Arc, RwLock,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use redis::{IntoConnectionInfo, aio::MultiplexedConnection};
+
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
+
+// HSET 모드에서 value를 저장하는 field 이름
+const HASH_FIELD: &str = "value";
+
+/// RESP 프로토콜을 쓰는 key-value store
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Flavor {
+    Redis,
+    Valkey,
+    KeyDB,
+    Dragonfly,
+    Kvrocks,
+}
+
+impl Flavor {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Flavor::Redis => "Redis",
+            Flavor::Valkey => "Valkey",
+            Flavor::KeyDB => "KeyDB",
+            Flavor::Dragonfly => "Dragonfly",
+            Flavor::Kvrocks => "Kvrocks",
+        }
+    }
+
+    // docker-compose.yml 기준 주소
+    fn default_url(&self) -> &'static str {
+        match self {
+            Flavor::Redis => "redis://127.0.0.1:16379/0",
+            Flavor::Valkey => "redis://127.0.0.1:16380/0",
+            Flavor::KeyDB => "redis://127.0.0.1:16381/0",
+            Flavor::Dragonfly => "redis://127.0.0.1:16382/0",
+            Flavor::Kvrocks => "redis://127.0.0.1:16666/0",
+        }
+    }
+
+    /// persistence 모드를 적용하는 CONFIG SET 목록 (지원하지 않으면 None)
+    fn persistence_config(
+        &self,
+        persistence: Persistence,
+    ) -> Option<Vec<(&'static str, &'static str)>> {
+        match (self, persistence) {
+            (Flavor::Redis | Flavor::Valkey | Flavor::KeyDB, Persistence::None) => {
+                Some(vec![("appendonly", "no"), ("save", "")])
+            }
+            (Flavor::Redis | Flavor::Valkey | Flavor::KeyDB, Persistence::EverySec) => {
+                Some(vec![("appendonly", "yes"), ("appendfsync", "everysec")])
+            }
+            (Flavor::Redis | Flavor::Valkey | Flavor::KeyDB, Persistence::Always) => {
+                Some(vec![("appendonly", "yes"), ("appendfsync", "always")])
+            }
+            // Dragonfly는 AOF가 없고 snapshot만 지원
+            (Flavor::Dragonfly, Persistence::None) => Some(vec![]),
+            (Flavor::Dragonfly, _) => None,
+            // Kvrocks는 항상 RocksDB에 쓰므로 WAL sync 여부로 대응
+            (Flavor::Kvrocks, Persistence::None) => None,
+            (Flavor::Kvrocks, Persistence::EverySec) => {
+                Some(vec![("rocksdb.write_options.sync", "no")])
+            }
+            (Flavor::Kvrocks, Persistence::Always) => {
+                Some(vec![("rocksdb.write_options.sync", "yes")])
+            }
+        }
+    }
+}
+
+/// AOF fsync 정책
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Persistence {
+    None,
+    EverySec,
+    Always,
+}
+
+impl Persistence {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "none" => Some(Persistence::None),
+            "everysec" => Some(Persistence::EverySec),
+            "always" => Some(Persistence::Always),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WriteCommand {
+    Set,
+    HSet,
+}
+
+pub struct RespDB {
+    flavor: Flavor,
+    // multiplexed connection을 여러 개 열어서 round-robin으로 사용
+    connections: Vec<MultiplexedConnection>,
+    next: AtomicUsize,
+    // None이면 서버 설정을 그대로 사용
+    persistence: Option<Persistence>,
+    // setup이 CONFIG SET으로 처음 바꾸기 전의 값 (restore_settings에서 되돌림)
+    saved_config: RwLock<Vec<(&'static str, String)>>,
+    command: WriteCommand,
+    // (replica 수, timeout ms)
+    wait: Option<(u32, u32)>,
+}
+
+impl RespDB {
+    pub async fn new(
+        flavor: Flavor,
+        config: &BackendConfig,
+    ) -> Result<Arc<dyn Database + Send + Sync>> {
+        config.reject_tls(flavor.name())?;
+
+        let persistence = match &config.persistence {
+            Some(name) => Some(Persistence::parse(name).ok_or_else(|| {
+                Errors::ConnectionError(format!("Unknown persistence mode: {}", name))
+            })?),
+            None => None,
+        };
+
+        let command = match config.command.as_deref() {
+            None | Some("set") => WriteCommand::Set,
+            Some("hset") => WriteCommand::HSet,
+            Some(other) => {
+                return Err(Errors::ConnectionError(format!(
+                    "Unknown write command: {}",
+                    other
+                )));
+            }
+        };
+
+        let mut connection_info = config
+            .url_or(flavor.default_url())
+            .into_connection_info()
+            .map_err(|e| Errors::from_redis(e, Errors::ConnectionError))?;
+        if config.username.is_some() {
+            connection_info.redis.username = config.username.clone();
+        }
+        if config.password.is_some() {
+            connection_info.redis.password = config.password.clone();
+        }
+
+        let client = redis::Client::open(connection_info)
+            .map_err(|e| Errors::from_redis(e, Errors::ConnectionError))?;
+
+        let mut connections = vec![];
+        for _ in 0..config.pool_size_or(16).max(1) {
+            let connection = client
+                .get_multiplexed_async_connection()
+                .await
+                .map_err(|e| Errors::from_redis(e, Errors::ConnectionError))?;
+            connections.push(connection);
+        }
+
+        Ok(Arc::new(RespDB {
+            flavor,
+            connections,
+            next: AtomicUsize::new(0),
+            persistence,
+            saved_config: RwLock::new(vec![]),
+            command,
+            wait: config
+                .wait_replicas
+                .map(|replicas| (replicas, config.wait_timeout_ms.unwrap_or(1000))),
+        }))
+    }
+
+    async fn config_set(&self, name: &str, value: &str) -> Result<()> {
+        redis::cmd("CONFIG")
+            .arg("SET")
+            .arg(name)
+            .arg(value)
+            .query_async::<()>(&mut self.connection())
+            .await
+            .map_err(|e| Errors::ConnectionError(format!("CONFIG SET {} failed: {}", name, e)))
+    }
+
+    fn connection(&self) -> MultiplexedConnection {
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.connections.len();
+        self.connections[index].clone()
+    }
+
+    /// entries를 한 pipeline으로 보내고, wait가 설정되어 있으면 WAIT까지 함께 보냄
+    async fn write_pipeline(&self, entries: &[(&str, &str)]) -> Result<()> {
+        let mut pipe = redis::pipe();
+        for (key, value) in entries {
+            match self.command {
+                WriteCommand::Set => pipe.set(*key, *value).ignore(),
+                WriteCommand::HSet => pipe.hset(*key, HASH_FIELD, *value).ignore(),
+            };
+        }
+
+        if let Some((replicas, timeout_ms)) = self.wait {
+            pipe.cmd("WAIT").arg(replicas).arg(timeout_ms);
+        }
+
+        let acked: Vec<u32> = pipe
+            .query_async(&mut self.connection())
+            .await
+            .map_err(|e| Errors::from_redis(e, Errors::WriteError))?;
+
+        // WAIT는 timeout이 지나면 그때까지 확인된 replica 수를 반환
+        if let (Some((replicas, _)), Some(&acked)) = (self.wait, acked.first())
+            && acked < replicas
+        {
+            return Err(Errors::Timeout(format!(
+                "WAIT acknowledged by {} of {} replicas",
+                acked, replicas
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Database for RespDB {
+    async fn ping(&self) -> Result<()> {
+        redis::cmd("PING")
+            .query_async::<String>(&mut self.connection())
+            .await
+            .map_err(|e| Errors::from_redis(e, Errors::ConnectionError))?;
+
+        Ok(())
+    }
+
+    async fn restore_settings(&self) -> Result<()> {
+        let saved = std::mem::take(&mut *self.saved_config.write().unwrap());
+        for (name, value) in saved {
+            self.config_set(name, &value).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        // durability가 있으면 config의 persistence보다 우선
+        let persistence = match durability {
+            None => self.persistence,
+            Some(Durability::Memory) => Some(Persistence::None),
+            Some(Durability::OsBuffered) => Some(Persistence::EverySec),
+            Some(Durability::Fsync) => Some(Persistence::Always),
+            // replica 확인은 WAIT로 하고 AOF는 config대로 둠
+            Some(durability @ Durability::Replicated) => {
+                if self.wait.is_none() {
+                    return Err(
+                        durability.unsupported(self.flavor.name(), "wait_replicas is not set")
+                    );
+                }
+
+                self.persistence
+            }
+        };
+
+        if let Some(persistence) = persistence {
+            let settings = self.flavor.persistence_config(persistence).ok_or_else(|| {
+                let reason = format!("persistence mode {:?} is not available", persistence);
+                match durability {
+                    Some(durability) => durability.unsupported(self.flavor.name(), &reason),
+                    None => Errors::Unsupported(format!("{} {}", self.flavor.name(), reason)),
+                }
+            })?;
+
+            for (name, value) in settings {
+                // 서버 전체 설정이므로 처음 값을 남겨 둠 (sweep 등으로 setup을 다시 해도 유지)
+                let saved = self
+                    .saved_config
+                    .read()
+                    .unwrap()
+                    .iter()
+                    .any(|(saved, _)| *saved == name);
+                if !saved {
+                    // CONFIG GET은 [name, value]를 반환
+                    let original: Vec<String> = redis::cmd("CONFIG")
+                        .arg("GET")
+                        .arg(name)
+                        .query_async(&mut self.connection())
+                        .await
+                        .map_err(|e| {
+                            Errors::ConnectionError(format!("CONFIG GET {} failed: {}", name, e))
+                        })?;
+                    if let Some(original) = original.into_iter().nth(1) {
+                        self.saved_config.write().unwrap().push((name, original));
+                    }
+                }
+
+                self.config_set(name, value).await?;
+            }
+        }
+
+        // 기존 데이터 삭제
+        redis::cmd("FLUSHDB")
+            .query_async::<()>(&mut self.connection())
+            .await
+            .map_err(|e| Errors::from_redis(e, Errors::ConnectionError))?;
+
+        Ok(())
+    }
+
+    async fn write(&self, key: &str, value: &str) -> Result<()> {
+        self.write_pipeline(&[(key, value)]).await
+    }
+
+    async fn write_batch(&self, entries: &[(String, String)]) -> Result<()> {
+        let entries: Vec<(&str, &str)> = entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+
+        self.write_pipeline(&entries).await
+    }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let mut connection = self.connection();
+
+        let value = match self.command {
+            WriteCommand::Set => {
+                redis::cmd("GET")
+                    .arg(key)
+                    .query_async(&mut connection)
+                    .await
+            }
+            WriteCommand::HSet => {
+                redis::cmd("HGET")
+                    .arg(key)
+                    .arg(HASH_FIELD)
+                    .query_async(&mut connection)
+                    .await
+            }
+        };
+
+        value.map_err(|e| Errors::from_redis(e, Errors::ReadError))
+    }
+
+    async fn count(&self) -> Result<u64> {
+        redis::cmd("DBSIZE")
+            .query_async(&mut self.connection())
+            .await
+            .map_err(|e| Errors::from_redis(e, Errors::ReadError))
+    }
+}
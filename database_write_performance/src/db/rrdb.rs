@@ -2,25 +2,33 @@ use std::sync::Arc;
 
 use sqlx::{PgPool, postgres::PgPoolOptions};
 
-use super::{Database, Errors, Result};
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
 
 #[derive(Debug)]
 pub struct Rrdb {
     pool: PgPool,
+    table: String,
 }
 
 impl Rrdb {
-    pub async fn new() -> Result<Arc<dyn Database + Send + Sync>> {
-        let connection_string = "postgres://rrdb@127.0.0.1:22208/rrdb";
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
+        let connection_string = config.url_with_credentials(
+            "postgres://rrdb@127.0.0.1:22208/rrdb",
+            ("sslmode", "require"),
+        )?;
 
         let pool = PgPoolOptions::new()
-            .max_connections(1000)
-            .min_connections(1000)
-            .connect(connection_string)
+            .max_connections(config.pool_size_or(1000))
+            .min_connections(config.min_pool_size_or(1000))
+            .connect(&connection_string)
             .await
-            .map_err(|error| Errors::ConnectionError(error.to_string()))?;
+            .map_err(|error| Errors::from_sqlx(error, Errors::ConnectionError))?;
 
-        Ok(Arc::new(Rrdb { pool }))
+        Ok(Arc::new(Rrdb {
+            pool,
+            table: config.table_or("key_value"),
+        }))
     }
 }
 
@@ -30,41 +38,74 @@ impl Database for Rrdb {
         sqlx::query("SELECT 1")
             .execute(&self.pool)
             .await
-            .map_err(|error| Errors::ConnectionError(error.to_string()))?;
+            .map_err(|error| Errors::from_sqlx(error, Errors::ConnectionError))?;
 
         Ok(())
     }
 
-    async fn setup(&self) -> Result<()> {
-        sqlx::query("DROP TABLE IF EXISTS key_value")
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        if let Some(durability) = durability {
+            return Err(durability.unsupported("RRDB", "durability is not configurable"));
+        }
+
+        sqlx::query(&format!("DROP TABLE IF EXISTS {table}", table = self.table))
             .execute(&self.pool)
             .await
-            .map_err(|error| Errors::WriteError(error.to_string()))?;
+            .map_err(|error| Errors::from_sqlx(error, Errors::WriteError))?;
 
-        sqlx::query(
-            "CREATE TABLE key_value (
+        sqlx::query(&format!(
+            "CREATE TABLE {table} (
                 key VARCHAR(255) PRIMARY KEY,
                 value VARCHAR(65535)
             )",
-        )
+            table = self.table
+        ))
         .execute(&self.pool)
         .await
-        .map_err(|error| Errors::WriteError(error.to_string()))?;
+        .map_err(|error| Errors::from_sqlx(error, Errors::WriteError))?;
 
         Ok(())
     }
 
     async fn write(&self, key: &str, value: &str) -> Result<()> {
-        sqlx::query("INSERT INTO key_value (key, value) VALUES ($1, $2)")
-            .bind(key)
-            .bind(value)
-            .execute(&self.pool)
-            .await
-            .map_err(|error| Errors::WriteError(error.to_string()))?;
+        sqlx::query(&format!(
+            "INSERT INTO {table} (key, value) VALUES ($1, $2)",
+            table = self.table
+        ))
+        .bind(key)
+        .bind(value)
+        .execute(&self.pool)
+        .await
+        .map_err(|error| Errors::from_sqlx(error, Errors::WriteError))?;
 
         Ok(())
     }
 
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let value = sqlx::query_scalar::<_, String>(&format!(
+            "SELECT value FROM {table} WHERE key = $1",
+            table = self.table
+        ))
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Errors::from_sqlx(e, Errors::ReadError))?;
+
+        Ok(value)
+    }
+
+    async fn count(&self) -> Result<u64> {
+        let count = sqlx::query_scalar::<_, i64>(&format!(
+            "SELECT COUNT(*) FROM {table}",
+            table = self.table
+        ))
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Errors::from_sqlx(e, Errors::ReadError))?;
+
+        Ok(count as u64)
+    }
+
     fn worker_count(&self) -> usize {
         1000
     }
@@ -1,22 +1,57 @@
+use scylla::query::Query;
+use scylla::statement::Consistency;
 use scylla::{Session, SessionBuilder};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
 
-use super::{Database, Errors, Result};
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
+use crate::record::Schema;
 
 #[derive(Debug)]
 pub struct CassandraDB {
     session: Session,
+    keyspace: String,
+    table: String,
+    // setup에서 durability로 정함 (None이면 driver 기본값)
+    consistency: RwLock<Option<Consistency>>,
+    // 같이 저장하는 value 조각 column 수 (--indexes의 최댓값, index는 앞쪽 일부에만 있음)
+    field_count: AtomicUsize,
 }
 
 impl CassandraDB {
-    pub async fn new() -> Result<Arc<dyn Database + Send + Sync>> {
-        let session = SessionBuilder::new()
-            .known_node("127.0.0.1:19043")
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
+        config.reject_tls("Cassandra")?;
+
+        let mut builder = SessionBuilder::new().known_node(config.url_or("127.0.0.1:19043"));
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            builder = builder.user(username, password);
+        }
+
+        let session = builder
             .build()
             .await
             .map_err(|error| Errors::ConnectionError(error.to_string()))?;
 
-        Ok(Arc::new(CassandraDB { session }))
+        Ok(Arc::new(CassandraDB {
+            session,
+            keyspace: config.database_or("benchmark"),
+            table: config.table_or("key_value"),
+            consistency: RwLock::new(None),
+            field_count: AtomicUsize::new(0),
+        }))
+    }
+
+    fn write_query(&self) -> Query {
+        let mut query = Query::new(super::cassandra::insert_statement(
+            &self.table,
+            self.field_count.load(Ordering::Relaxed),
+        ));
+        if let Some(consistency) = *self.consistency.read().unwrap() {
+            query.set_consistency(consistency);
+        }
+
+        query
     }
 }
 
@@ -32,56 +67,174 @@ impl Database for CassandraDB {
         Ok(())
     }
 
-    async fn setup(&self) -> Result<()> {
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        let (replication_factor, durable_writes, consistency) = match durability {
+            Some(durability) => {
+                let (replication_factor, durable_writes, consistency) =
+                    super::cassandra::keyspace_durability(&self.session, durability, "Cassandra")
+                        .await?;
+                (replication_factor, durable_writes, Some(consistency))
+            }
+            None => (1, true, None),
+        };
+        *self.consistency.write().unwrap() = consistency;
+
+        let options = format!(
+            "WITH REPLICATION = {{
+                 'class': 'SimpleStrategy',
+                 'replication_factor': {replication_factor}
+             }} AND DURABLE_WRITES = {durable_writes}"
+        );
+
         // 키스페이스 생성
         self.session
             .query_unpaged(
-                "CREATE KEYSPACE IF NOT EXISTS benchmark 
-                 WITH REPLICATION = {
-                     'class': 'SimpleStrategy',
-                     'replication_factor': 1
-                 }",
+                format!(
+                    "CREATE KEYSPACE IF NOT EXISTS {keyspace} {options}",
+                    keyspace = self.keyspace
+                ),
                 (),
             )
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
+
+        // 이미 있던 keyspace에도 durability 설정을 적용
+        if durability.is_some() {
+            self.session
+                .query_unpaged(
+                    format!(
+                        "ALTER KEYSPACE {keyspace} {options}",
+                        keyspace = self.keyspace
+                    ),
+                    (),
+                )
+                .await
+                .map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
+        }
 
         // 키스페이스 사용
         self.session
-            .use_keyspace("benchmark", false)
+            .use_keyspace(&self.keyspace, false)
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
 
         // 기존 테이블 삭제 (있다면)
         self.session
-            .query_unpaged("DROP TABLE IF EXISTS key_value", ())
+            .query_unpaged(
+                format!("DROP TABLE IF EXISTS {table}", table = self.table),
+                (),
+            )
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
 
         // 새 테이블 생성
         self.session
             .query_unpaged(
-                "CREATE TABLE key_value (
+                format!(
+                    "CREATE TABLE {table} (
                     key TEXT PRIMARY KEY,
                     value TEXT
                 )",
+                    table = self.table
+                ),
                 (),
             )
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
+
+        Ok(())
+    }
+
+    async fn create_indexes(
+        &self,
+        count: usize,
+        field_count: usize,
+        _schema: Option<&Schema>,
+    ) -> Result<()> {
+        super::cassandra::create_value_indexes(
+            &self.session,
+            &self.table,
+            count,
+            field_count,
+            Some("sai"),
+        )
+        .await?;
+        self.field_count.store(field_count, Ordering::Relaxed);
 
         Ok(())
     }
 
     async fn write(&self, key: &str, value: &str) -> Result<()> {
-        self.session
+        let field_count = self.field_count.load(Ordering::Relaxed);
+        let result = if field_count > 0 {
+            let values = super::cassandra::row_values(key, value, field_count);
+            self.session.query_unpaged(self.write_query(), values).await
+        } else {
+            self.session
+                .query_unpaged(self.write_query(), (key, value))
+                .await
+        };
+        result.map_err(|e| Errors::from_scylla(e, Errors::WriteError))?;
+
+        Ok(())
+    }
+
+    async fn write_batch(&self, entries: &[(String, String)]) -> Result<()> {
+        let field_count = self.field_count.load(Ordering::Relaxed);
+        super::cassandra::write_unlogged_batch(
+            &self.session,
+            self.write_query(),
+            entries,
+            field_count,
+        )
+        .await
+    }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let result = self
+            .session
             .query_unpaged(
-                "INSERT INTO key_value (key, value) VALUES (?, ?)",
-                (key, value),
+                format!(
+                    "SELECT value FROM {table} WHERE key = ?",
+                    table = self.table
+                ),
+                (key,),
             )
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_scylla(e, Errors::ReadError))?
+            .into_rows_result()
+            .map_err(|e| Errors::ReadError(e.to_string()))?;
 
-        Ok(())
+        let row = result
+            .maybe_first_row::<(Option<String>,)>()
+            .map_err(|e| Errors::ReadError(e.to_string()))?;
+
+        Ok(row.and_then(|(value,)| value))
+    }
+
+    async fn scan(&self, start_key: &str, count: usize) -> Result<Vec<(String, String)>> {
+        // partition key는 hash되므로 token 순서로 range scan
+        let result = self
+            .session
+            .query_unpaged(
+                format!(
+                    "SELECT key, value FROM {table} WHERE token(key) >= token(?) LIMIT ?",
+                    table = self.table
+                ),
+                (start_key, count as i32),
+            )
+            .await
+            .map_err(|e| Errors::from_scylla(e, Errors::ReadError))?
+            .into_rows_result()
+            .map_err(|e| Errors::ReadError(e.to_string()))?;
+
+        let rows = result
+            .rows::<(String, Option<String>)>()
+            .map_err(|e| Errors::ReadError(e.to_string()))?
+            .map(|row| row.map(|(key, value)| (key, value.unwrap_or_default())))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| Errors::ReadError(e.to_string()))?;
+
+        Ok(rows)
     }
 }
@@ -1,25 +1,32 @@
 use sqlx::{MySqlPool, mysql::MySqlPoolOptions};
 use std::sync::Arc;
 
-use super::{Database, Errors, Result};
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
+use crate::record::Schema;
 
 #[derive(Debug)]
 pub struct TiDB {
     pool: MySqlPool,
+    table: String,
 }
 
 impl TiDB {
-    pub async fn new() -> Result<Arc<dyn Database + Send + Sync>> {
-        let connection_string = "mysql://root@127.0.0.1:4000/test";
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
+        let connection_string = config
+            .url_with_credentials("mysql://root@127.0.0.1:4000/test", ("ssl-mode", "REQUIRED"))?;
 
         let pool = MySqlPoolOptions::new()
-            .max_connections(1000) // TiDB에 적합한 연결 수
-            .min_connections(500)
-            .connect(connection_string)
+            .max_connections(config.pool_size_or(1000)) // TiDB에 적합한 연결 수
+            .min_connections(config.min_pool_size_or(500))
+            .connect(&connection_string)
             .await
-            .map_err(|error| Errors::ConnectionError(error.to_string()))?;
+            .map_err(|error| Errors::from_sqlx(error, Errors::ConnectionError))?;
 
-        Ok(Arc::new(TiDB { pool }))
+        Ok(Arc::new(TiDB {
+            pool,
+            table: config.table_or("key_value"),
+        }))
     }
 }
 
@@ -29,42 +36,101 @@ impl Database for TiDB {
         sqlx::query("SELECT 1")
             .execute(&self.pool)
             .await
-            .map_err(|error| Errors::ConnectionError(error.to_string()))?;
+            .map_err(|error| Errors::from_sqlx(error, Errors::ConnectionError))?;
         Ok(())
     }
 
-    async fn setup(&self) -> Result<()> {
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        // TiKV는 Raft log를 항상 fsync하고 quorum에 복제한 뒤 응답함
+        if let Some(durability @ (Durability::Memory | Durability::OsBuffered)) = durability {
+            return Err(durability.unsupported("TiDB", "TiKV always syncs the Raft log"));
+        }
+
         // 테이블이 존재하면 삭제
-        sqlx::query("DROP TABLE IF EXISTS key_value")
+        sqlx::query(&format!("DROP TABLE IF EXISTS {table}", table = self.table))
             .execute(&self.pool)
             .await
-            .map_err(|e| Errors::WriteError(e.to_string()))?;
+            .map_err(|e| Errors::from_sqlx(e, Errors::WriteError))?;
 
         // 새 테이블 생성 (TiDB에 최적화)
-        sqlx::query(
-            "CREATE TABLE `key_value` (
+        sqlx::query(&format!(
+            "CREATE TABLE {table} (
                 `key` VARCHAR(255) PRIMARY KEY,
                 `value` VARCHAR(1000) NOT NULL
             )",
-        )
+            table = self.table
+        ))
         .execute(&self.pool)
         .await
-        .map_err(|e| Errors::WriteError(e.to_string()))?;
+        .map_err(|e| Errors::from_sqlx(e, Errors::WriteError))?;
 
         Ok(())
     }
 
+    async fn create_indexes(
+        &self,
+        count: usize,
+        _field_count: usize,
+        _schema: Option<&Schema>,
+    ) -> Result<()> {
+        super::mysql::create_value_indexes(&self.pool, &self.table, count).await
+    }
+
     async fn write(&self, key: &str, value: &str) -> Result<()> {
-        sqlx::query(
-            "INSERT INTO key_value (`key`, `value`) VALUES (?, ?) 
+        sqlx::query(&format!(
+            "INSERT INTO {table} (`key`, `value`) VALUES (?, ?) 
              ON DUPLICATE KEY UPDATE `value` = VALUES(`value`)",
-        )
+            table = self.table
+        ))
         .bind(key)
         .bind(value)
         .execute(&self.pool)
         .await
-        .map_err(|e| Errors::WriteError(e.to_string()))?;
+        .map_err(|e| Errors::from_sqlx(e, Errors::WriteError))?;
 
         Ok(())
     }
+
+    async fn storage_size(&self) -> Result<u64> {
+        super::mysql::table_size(&self.pool, &self.table).await
+    }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let value = sqlx::query_scalar::<_, String>(&format!(
+            "SELECT `value` FROM {table} WHERE `key` = ?",
+            table = self.table
+        ))
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Errors::from_sqlx(e, Errors::ReadError))?;
+
+        Ok(value)
+    }
+
+    async fn count(&self) -> Result<u64> {
+        let count = sqlx::query_scalar::<_, i64>(&format!(
+            "SELECT COUNT(*) FROM {table}",
+            table = self.table
+        ))
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Errors::from_sqlx(e, Errors::ReadError))?;
+
+        Ok(count as u64)
+    }
+
+    async fn scan(&self, start_key: &str, count: usize) -> Result<Vec<(String, String)>> {
+        let rows = sqlx::query_as::<_, (String, String)>(&format!(
+            "SELECT `key`, `value` FROM {table} WHERE `key` >= ? ORDER BY `key` LIMIT ?",
+            table = self.table
+        ))
+        .bind(start_key)
+        .bind(count as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Errors::from_sqlx(e, Errors::ReadError))?;
+
+        Ok(rows)
+    }
 }
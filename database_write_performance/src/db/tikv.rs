@@ -1,15 +1,23 @@
 use std::sync::Arc;
 use tikv_client::RawClient;
 
-use super::{Database, Errors, Result};
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
 
 pub struct TiKV {
     client: RawClient,
 }
 
 impl TiKV {
-    pub async fn new() -> Result<Arc<dyn Database + Send + Sync>> {
-        let pd_endpoints = vec!["localhost:12379"];
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
+        config.reject_tls("TiKV")?;
+
+        // PD endpoint는 ,로 구분
+        let pd_endpoints: Vec<String> = config
+            .url_or("localhost:12379")
+            .split(',')
+            .map(|endpoint| endpoint.trim().to_string())
+            .collect();
 
         let client = RawClient::new(pd_endpoints)
             .await
@@ -31,7 +39,12 @@ impl Database for TiKV {
         }
     }
 
-    async fn setup(&self) -> Result<()> {
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        // Raft log를 항상 fsync하고 quorum에 복제한 뒤 응답함
+        if let Some(durability @ (Durability::Memory | Durability::OsBuffered)) = durability {
+            return Err(durability.unsupported("TiKV", "the Raft log is always synced"));
+        }
+
         // TiKV는 schema-less이므로 별도 테이블 생성이 필요 없음
         // 기존 벤치마크 키들을 정리 (선택적)
 
@@ -68,8 +81,62 @@ impl Database for TiKV {
         self.client
             .put(namespaced_key.into_bytes(), value.as_bytes().to_vec())
             .await
-            .map_err(|_| Errors::WriteError("Failed to write to TiKV".to_string()))?;
+            .map_err(|e| Errors::from_tikv(e, Errors::WriteError))?;
+
+        Ok(())
+    }
+
+    async fn write_batch(&self, entries: &[(String, String)]) -> Result<()> {
+        let pairs: Vec<(Vec<u8>, Vec<u8>)> = entries
+            .iter()
+            .map(|(key, value)| {
+                (
+                    format!("benchmark:{}", key).into_bytes(),
+                    value.as_bytes().to_vec(),
+                )
+            })
+            .collect();
+
+        self.client
+            .batch_put(pairs)
+            .await
+            .map_err(|e| Errors::from_tikv(e, Errors::WriteError))?;
 
         Ok(())
     }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let namespaced_key = format!("benchmark:{}", key);
+
+        let value = self
+            .client
+            .get(namespaced_key.into_bytes())
+            .await
+            .map_err(|e| Errors::from_tikv(e, Errors::ReadError))?;
+
+        Ok(value.map(|bytes| String::from_utf8_lossy(&bytes).into_owned()))
+    }
+
+    async fn scan(&self, start_key: &str, count: usize) -> Result<Vec<(String, String)>> {
+        let start = format!("benchmark:{}", start_key).into_bytes();
+        let end = b"benchmark;".to_vec();
+
+        let kvs = self
+            .client
+            .scan(start..end, count as u32)
+            .await
+            .map_err(|e| Errors::from_tikv(e, Errors::ReadError))?;
+
+        Ok(kvs
+            .into_iter()
+            .map(|kv| {
+                let key: Vec<u8> = kv.0.into();
+                let key = String::from_utf8_lossy(&key);
+                (
+                    key.trim_start_matches("benchmark:").to_string(),
+                    String::from_utf8_lossy(&kv.1).into_owned(),
+                )
+            })
+            .collect())
+    }
 }
@@ -0,0 +1,187 @@
+use std::{sync::Arc, time::Duration};
+
+use ydb::{ClientBuilder, QueryClient, StaticCredentials, Value, ydb_params, ydb_struct};
+
+use super::{Database, Durability, Errors, Result};
+use crate::config::BackendConfig;
+
+pub struct Ydb {
+    client: QueryClient,
+    table: String,
+}
+
+impl Ydb {
+    pub async fn new(config: &BackendConfig) -> Result<Arc<dyn Database + Send + Sync>> {
+        let mut endpoint = config.url_or("grpc://localhost:2136");
+        if config.tls() {
+            endpoint = endpoint.replacen("grpc://", "grpcs://", 1);
+        }
+        let database = config.database_or("/local");
+
+        let mut builder =
+            ClientBuilder::new_from_connection_string(format!("{}{}", endpoint, database))
+                .map_err(|e| Errors::ConnectionError(e.to_string()))?;
+
+        // username/password가 설정된 경우 static credentials 사용 (local-ydb는 인증 없음)
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            let uri = endpoint
+                .parse()
+                .map_err(|e: http::uri::InvalidUri| Errors::ConnectionError(e.to_string()))?;
+
+            builder = builder.with_credentials(StaticCredentials::new(
+                username.clone(),
+                password.clone(),
+                uri,
+                database.clone(),
+            ));
+        }
+
+        // discovery가 끝나지 않으면 build가 반환되지 않으므로 timeout 설정
+        let client = tokio::time::timeout(Duration::from_secs(10), builder.build())
+            .await
+            .map_err(|_| Errors::ConnectionError("Connection timeout".into()))?
+            .map_err(|e| Errors::ConnectionError(e.to_string()))?;
+
+        Ok(Arc::new(Ydb {
+            client: client.query_client(),
+            table: config.table_or("key_value"),
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Database for Ydb {
+    async fn ping(&self) -> Result<()> {
+        self.client
+            .clone()
+            .exec("SELECT 1")
+            .await
+            .map_err(|e| Errors::ConnectionError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn setup(&self, durability: Option<Durability>) -> Result<()> {
+        // 분산 storage에 sync로 복제한 뒤 응답함
+        if let Some(durability @ (Durability::Memory | Durability::OsBuffered)) = durability {
+            return Err(
+                durability.unsupported("YDB", "writes are always synced to distributed storage")
+            );
+        }
+
+        // 테이블이 존재하면 삭제
+        self.client
+            .clone()
+            .exec(format!("DROP TABLE IF EXISTS `{}`", self.table))
+            .await
+            .map_err(|e| Errors::ConnectionError(e.to_string()))?;
+
+        // row table로 생성 (column table은 UPSERT 단건 쓰기에 적합하지 않음)
+        self.client
+            .clone()
+            .exec(format!(
+                "CREATE TABLE `{}` (
+                    key Utf8 NOT NULL,
+                    value Utf8,
+                    PRIMARY KEY (key)
+                ) WITH (STORE = ROW)",
+                self.table
+            ))
+            .await
+            .map_err(|e| Errors::ConnectionError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn write(&self, key: &str, value: &str) -> Result<()> {
+        self.client
+            .clone()
+            .exec(format!(
+                "DECLARE $key AS Utf8;
+                DECLARE $value AS Utf8;
+                UPSERT INTO `{}` (key, value) VALUES ($key, $value)",
+                self.table
+            ))
+            .param("$key", key)
+            .param("$value", value)
+            .await
+            .map_err(|e| Errors::from_ydb(e, Errors::WriteError))?;
+
+        Ok(())
+    }
+
+    async fn write_batch(&self, entries: &[(String, String)]) -> Result<()> {
+        // List<Struct>를 AS_TABLE로 펼쳐서 한 번에 UPSERT
+        let rows = entries
+            .iter()
+            .map(|(key, value)| {
+                ydb_struct!(
+                    "key" => key.as_str(),
+                    "value" => value.as_str(),
+                )
+            })
+            .collect();
+
+        let example = ydb_struct!(
+            "key" => "",
+            "value" => "",
+        );
+
+        let list =
+            Value::list_from(example, rows).map_err(|e| Errors::from_ydb(e, Errors::WriteError))?;
+
+        self.client
+            .clone()
+            .exec(format!(
+                "UPSERT INTO `{}` SELECT * FROM AS_TABLE($rows)",
+                self.table
+            ))
+            .params(ydb_params!("$rows" => list))
+            .await
+            .map_err(|e| Errors::from_ydb(e, Errors::WriteError))?;
+
+        Ok(())
+    }
+
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        let result_set = self
+            .client
+            .clone()
+            .query_result_set(format!(
+                "DECLARE $key AS Utf8;
+                SELECT value FROM `{}` WHERE key = $key",
+                self.table
+            ))
+            .param("$key", key)
+            .await
+            .map_err(|e| Errors::from_ydb(e, Errors::ReadError))?;
+
+        match result_set.rows().next() {
+            Some(mut row) => {
+                let value: Option<String> = row
+                    .remove_field_by_name("value")
+                    .and_then(|value| value.try_into())
+                    .map_err(|e| Errors::from_ydb(e, Errors::ReadError))?;
+
+                Ok(value)
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn count(&self) -> Result<u64> {
+        let mut row = self
+            .client
+            .clone()
+            .query_row(format!("SELECT COUNT(*) AS count FROM `{}`", self.table))
+            .await
+            .map_err(|e| Errors::from_ydb(e, Errors::ReadError))?;
+
+        let count: u64 = row
+            .remove_field_by_name("count")
+            .and_then(|value| value.try_into())
+            .map_err(|e| Errors::from_ydb(e, Errors::ReadError))?;
+
+        Ok(count)
+    }
+}
@@ -0,0 +1,197 @@
+use rand::Rng;
+
+// YCSB 기본값
+pub const ZIPFIAN_CONSTANT: f64 = 0.99;
+pub const HOTSPOT_DATA_FRACTION: f64 = 0.2;
+pub const HOTSPOT_OPERATION_FRACTION: f64 = 0.8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyDistribution {
+    Uniform,
+    Zipfian,
+    Latest,
+    Hotspot,
+}
+
+impl KeyDistribution {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "uniform" => Some(KeyDistribution::Uniform),
+            "zipfian" => Some(KeyDistribution::Zipfian),
+            "latest" => Some(KeyDistribution::Latest),
+            "hotspot" => Some(KeyDistribution::Hotspot),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyDistribution::Uniform => "uniform",
+            KeyDistribution::Zipfian => "zipfian",
+            KeyDistribution::Latest => "latest",
+            KeyDistribution::Hotspot => "hotspot",
+        }
+    }
+}
+
+/// key 목록의 index를 분포에 따라 선택
+/// item_count는 insert로 늘어날 수 있으므로 매 호출마다 전달받음
+pub struct KeyChooser {
+    distribution: KeyDistribution,
+    zipfian: ZipfianGenerator,
+}
+
+impl KeyChooser {
+    pub fn new(distribution: KeyDistribution, item_count: usize) -> Self {
+        KeyChooser {
+            distribution,
+            zipfian: ZipfianGenerator::new(item_count, ZIPFIAN_CONSTANT),
+        }
+    }
+
+    pub fn next_index<R: Rng>(&mut self, rng: &mut R, item_count: usize) -> usize {
+        match self.distribution {
+            KeyDistribution::Uniform => rng.random_range(0..item_count),
+            // YCSB ScrambledZipfianGenerator와 같이 순위를 hash해서 인기 key를 key 공간에 흩뜨림
+            // (hash 충돌로 선택되지 않는 key가 생기는 것도 YCSB와 같음)
+            KeyDistribution::Zipfian => {
+                let rank = self.zipfian.next(rng, item_count);
+                (fnv_hash64(rank as u64) % item_count as u64) as usize
+            }
+            // 가장 최근에 추가된 key일수록 자주 선택됨
+            KeyDistribution::Latest => item_count - 1 - self.zipfian.next(rng, item_count),
+            KeyDistribution::Hotspot => {
+                let hot_count = ((item_count as f64 * HOTSPOT_DATA_FRACTION) as usize).max(1);
+
+                if hot_count >= item_count || rng.random::<f64>() < HOTSPOT_OPERATION_FRACTION {
+                    rng.random_range(0..hot_count)
+                } else {
+                    rng.random_range(hot_count..item_count)
+                }
+            }
+        }
+    }
+}
+
+/// Gray et al. "Quickly Generating Billion-Record Synthetic Databases" 방식의 zipfian 생성기.
+/// YCSB ZipfianGenerator와 같은 알고리즘이며, item 수가 늘어나면 zeta를 증분 계산한다.
+/// 반환값은 순위라서 0에 가까울수록 자주 나옴 (key 선택에는 KeyChooser가 hash해서 사용)
+pub struct ZipfianGenerator {
+    theta: f64,
+    alpha: f64,
+    zeta2theta: f64,
+    item_count: usize,
+    zetan: f64,
+    eta: f64,
+}
+
+impl ZipfianGenerator {
+    pub fn new(item_count: usize, theta: f64) -> Self {
+        let zeta2theta = zeta(0, 2, theta, 0.0);
+        let zetan = zeta(0, item_count, theta, 0.0);
+
+        let mut generator = ZipfianGenerator {
+            theta,
+            alpha: 1.0 / (1.0 - theta),
+            zeta2theta,
+            item_count,
+            zetan,
+            eta: 0.0,
+        };
+        generator.eta = generator.eta();
+
+        generator
+    }
+
+    fn eta(&self) -> f64 {
+        (1.0 - (2.0 / self.item_count as f64).powf(1.0 - self.theta))
+            / (1.0 - self.zeta2theta / self.zetan)
+    }
+
+    pub fn next<R: Rng>(&mut self, rng: &mut R, item_count: usize) -> usize {
+        if item_count != self.item_count {
+            // item 수가 줄어드는 경우는 전체를 다시 계산
+            self.zetan = if item_count > self.item_count {
+                zeta(self.item_count, item_count, self.theta, self.zetan)
+            } else {
+                zeta(0, item_count, self.theta, 0.0)
+            };
+            self.item_count = item_count;
+            self.eta = self.eta();
+        }
+
+        let u: f64 = rng.random();
+        let uz = u * self.zetan;
+
+        if uz < 1.0 {
+            return 0;
+        }
+
+        if uz < 1.0 + 0.5_f64.powf(self.theta) {
+            return 1.min(item_count - 1);
+        }
+
+        let index = (item_count as f64 * (self.eta * u - self.eta + 1.0).powf(self.alpha)) as usize;
+
+        index.min(item_count - 1)
+    }
+}
+
+// sum(1 / i^theta) for i in (start, end]
+fn zeta(start: usize, end: usize, theta: f64, initial_sum: f64) -> f64 {
+    (start..end).fold(initial_sum, |sum, i| {
+        sum + 1.0 / ((i + 1) as f64).powf(theta)
+    })
+}
+
+// YCSB Utils.fnvhash64와 같은 FNV-1a 64bit hash (8 byte를 낮은 byte부터, 부호 있는 값의 절댓값)
+fn fnv_hash64(value: u64) -> u64 {
+    const OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01B3;
+
+    let hash = (0..8).fold(OFFSET_BASIS, |hash, i| {
+        (hash ^ ((value >> (i * 8)) & 0xff)).wrapping_mul(PRIME)
+    });
+
+    (hash as i64).unsigned_abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    #[test]
+    fn parses_names_case_insensitively() {
+        assert_eq!(
+            KeyDistribution::parse("Zipfian"),
+            Some(KeyDistribution::Zipfian)
+        );
+        assert_eq!(
+            KeyDistribution::parse("HOTSPOT"),
+            Some(KeyDistribution::Hotspot)
+        );
+        assert_eq!(KeyDistribution::parse("normal"), None);
+    }
+
+    #[test]
+    fn zipfian_keys_are_scrambled() {
+        let item_count = 1000;
+        let mut chooser = KeyChooser::new(KeyDistribution::Zipfian, item_count);
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let mut counts = vec![0; item_count];
+        for _ in 0..100_000 {
+            counts[chooser.next_index(&mut rng, item_count)] += 1;
+        }
+
+        // 가장 인기 있는 key는 순위 0을 hash한 위치
+        let hottest = (0..item_count).max_by_key(|index| counts[*index]).unwrap();
+        assert_eq!(hottest, (fnv_hash64(0) % item_count as u64) as usize);
+
+        // 인기 key 10개가 key 공간 앞쪽에 몰려 있지 않음
+        let mut ranked: Vec<usize> = (0..item_count).collect();
+        ranked.sort_by_key(|index| std::cmp::Reverse(counts[*index]));
+        assert!(ranked[..10].iter().any(|index| *index >= 100));
+    }
+}
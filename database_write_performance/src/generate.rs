@@ -1,41 +1,536 @@
-use rand::Rng;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use clap::{Parser, ValueEnum};
+use database_write_performance::record::{self, FieldType, Schema};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_json::json;
+
+// UUIDv7, snowflake key의 가상 시계 시작점 (2025-01-01T00:00:00Z)
+const EPOCH_MILLIS: u64 = 1_735_689_600_000;
+
+// 가상 시계에서 1ms 동안 만들어지는 key 수
+const KEYS_PER_MILLI: u64 = 100;
+
+// 실제 key-value cache의 value 크기 분포를 단순화한 것 (상한 byte, 비중)
+const DEFAULT_HISTOGRAM: &[(usize, f64)] = &[
+    (16, 5.0),
+    (32, 10.0),
+    (64, 20.0),
+    (128, 25.0),
+    (256, 18.0),
+    (512, 10.0),
+    (1024, 6.0),
+    (4096, 4.0),
+    (16384, 2.0),
+];
+
+// compressible payload에 쓰는 단어
+const WORDS: &[&str] = &[
+    "the", "quick", "brown", "fox", "jumps", "over", "lazy", "dog", "lorem", "ipsum", "dolor",
+    "sit", "amet", "write", "read", "commit", "index", "table", "row", "value", "key", "order",
+    "user", "event", "status", "active", "pending", "created", "updated", "deleted",
+];
+
+const ALPHANUMERIC: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+#[derive(Parser)]
+#[command(name = "Dataset Generator")]
+#[command(about = "Generate a key,value CSV dataset for the benchmark", long_about = None)]
+struct Cli {
+    #[arg(long)]
+    /// Output file (dataset.csv, or dataset.jsonl with --schema)
+    output: Option<PathBuf>,
+
+    #[arg(long, default_value = "1000000")]
+    /// Number of rows (was fixed at 100,000 before; use --rows 100000 for the old size)
+    rows: u64,
+
+    #[arg(long, default_value = "0")]
+    /// Random seed (the same seed and options always produce the same file)
+    seed: u64,
+
+    #[arg(long, value_enum, default_value = "uuid4")]
+    /// Key scheme
+    keys: KeyScheme,
+
+    #[arg(long, default_value = "100")]
+    /// Number of shared prefixes for the prefixed key scheme
+    prefixes: u64,
+
+    #[arg(long, value_enum, default_value = "uniform")]
+    /// Value size distribution
+    value_size: SizeDistribution,
+
+    #[arg(long, default_value = "20")]
+    /// Minimum value size in bytes
+    value_min: usize,
+
+    #[arg(long, default_value = "200")]
+    /// Maximum value size in bytes
+    value_max: usize,
+
+    #[arg(long, default_value = "100")]
+    /// Value size for fixed, median for lognormal
+    value_mean: usize,
+
+    #[arg(long, default_value = "1.0")]
+    /// Sigma for lognormal value sizes
+    value_sigma: f64,
+
+    #[arg(long)]
+    /// Value size histogram file ("max_size,weight" per line), built-in histogram if omitted
+    histogram: Option<PathBuf>,
+
+    #[arg(long, value_enum, default_value = "random")]
+    /// Value payload (random is alphanumeric; older versions wrote lowercase letters only)
+    payload: Payload,
+
+    #[arg(long)]
+    /// Schema file: write JSON Lines records with typed fields instead of key,value CSV
+    /// (text fields use the value size and payload options)
+    schema: Option<PathBuf>,
+
+    #[arg(long, default_value = "100")]
+    /// Number of distinct values of each tag field
+    tag_cardinality: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum KeyScheme {
+    // 0부터 증가하는 숫자 (0으로 채워서 문자열 순서와 같음)
+    Sequential,
+    // 무작위 순서
+    Uuid4,
+    // 시간 순서 + 무작위
+    Uuid7,
+    // 64bit (timestamp, worker, sequence)
+    Snowflake,
+    // 순번을 hash한 값 (충돌 없이 무작위 순서)
+    Hashed,
+    // 공유 prefix + prefix별 순번 (prefix 수만큼의 append 지점)
+    Prefixed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum SizeDistribution {
+    Fixed,
+    Uniform,
+    Lognormal,
+    Histogram,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Payload {
+    // 무작위 영숫자 (거의 압축되지 않음)
+    Random,
+    // 적은 수의 단어 반복 (일반 텍스트처럼 압축됨)
+    Compressible,
+}
+
+struct KeyGenerator {
+    scheme: KeyScheme,
+    width: usize,
+    prefixes: u64,
+    // prefix별 다음 순번
+    prefix_counters: Vec<u64>,
+    // snowflake: (마지막 timestamp, sequence)
+    snowflake: (u64, u64),
+}
+
+impl KeyGenerator {
+    fn new(scheme: KeyScheme, rows: u64, prefixes: u64) -> Self {
+        let prefixes = prefixes.max(1);
+        let prefix_counters = match scheme {
+            KeyScheme::Prefixed => vec![0; prefixes as usize],
+            _ => vec![],
+        };
+
+        KeyGenerator {
+            scheme,
+            width: rows.saturating_sub(1).max(1).to_string().len(),
+            prefixes,
+            prefix_counters,
+            snowflake: (0, 0),
+        }
+    }
+
+    fn next(&mut self, index: u64, rng: &mut StdRng) -> String {
+        let millis = EPOCH_MILLIS + index / KEYS_PER_MILLI;
+
+        match self.scheme {
+            KeyScheme::Sequential => format!("{:0width$}", index, width = self.width),
+            KeyScheme::Uuid4 => uuid::Builder::from_random_bytes(rng.random())
+                .into_uuid()
+                .to_string(),
+            KeyScheme::Uuid7 => uuid::Builder::from_unix_timestamp_millis(millis, &rng.random())
+                .into_uuid()
+                .to_string(),
+            KeyScheme::Snowflake => {
+                // 41bit timestamp, 10bit worker, 12bit sequence
+                let (last, sequence) = &mut self.snowflake;
+                *sequence = if *last == millis {
+                    (*sequence + 1) & 0xfff
+                } else {
+                    0
+                };
+                *last = millis;
+
+                let worker: u64 = rng.random_range(0..1024);
+                ((millis - EPOCH_MILLIS) << 22 | worker << 12 | *sequence).to_string()
+            }
+            KeyScheme::Hashed => format!("{:016x}", splitmix64(index)),
+            KeyScheme::Prefixed => {
+                let prefix = rng.random_range(0..self.prefixes);
+                let counter = &mut self.prefix_counters[prefix as usize];
+                *counter += 1;
+
+                format!(
+                    "p{:0prefix_width$}/{:0width$}",
+                    prefix,
+                    *counter - 1,
+                    prefix_width = (self.prefixes - 1).max(1).to_string().len(),
+                    width = self.width
+                )
+            }
+        }
+    }
+}
+
+// 1:1 대응하는 64bit mix 함수 (서로 다른 입력은 서로 다른 출력)
+fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+enum ValueSize {
+    Fixed(usize),
+    Uniform {
+        min: usize,
+        max: usize,
+    },
+    LogNormal {
+        median: f64,
+        sigma: f64,
+        min: usize,
+        max: usize,
+    },
+    // (상한, 누적 비중)
+    Histogram(Vec<(usize, f64)>),
+}
+
+impl ValueSize {
+    fn from_cli(cli: &Cli) -> Result<Self, String> {
+        if cli.value_min > cli.value_max {
+            return Err(format!(
+                "--value-min {} is larger than --value-max {}",
+                cli.value_min, cli.value_max
+            ));
+        }
+
+        Ok(match cli.value_size {
+            SizeDistribution::Fixed => ValueSize::Fixed(cli.value_mean),
+            SizeDistribution::Uniform => ValueSize::Uniform {
+                min: cli.value_min,
+                max: cli.value_max,
+            },
+            SizeDistribution::Lognormal => ValueSize::LogNormal {
+                median: cli.value_mean as f64,
+                sigma: cli.value_sigma,
+                min: cli.value_min,
+                max: cli.value_max,
+            },
+            SizeDistribution::Histogram => {
+                let buckets = match &cli.histogram {
+                    Some(path) => load_histogram(path)?,
+                    None => DEFAULT_HISTOGRAM.to_vec(),
+                };
+
+                let mut total = 0.0;
+                let cumulative = buckets
+                    .into_iter()
+                    .map(|(max, weight)| {
+                        total += weight;
+                        (max, total)
+                    })
+                    .collect::<Vec<_>>();
+
+                if total <= 0.0 {
+                    return Err("Histogram has no positive weight".into());
+                }
+
+                ValueSize::Histogram(cumulative)
+            }
+        })
+    }
+
+    fn sample(&self, rng: &mut StdRng) -> usize {
+        match self {
+            ValueSize::Fixed(size) => *size,
+            ValueSize::Uniform { min, max } => rng.random_range(*min..=*max),
+            ValueSize::LogNormal {
+                median,
+                sigma,
+                min,
+                max,
+            } => {
+                let size = median * (sigma * standard_normal(rng)).exp();
+                (size.round() as usize).clamp(*min, *max)
+            }
+            ValueSize::Histogram(buckets) => {
+                // 누적 비중으로 bucket을 고르고 bucket 안에서는 균등 분포
+                let total = buckets.last().map_or(0.0, |(_, total)| *total);
+                let target = rng.random::<f64>() * total;
+                let index = buckets
+                    .iter()
+                    .position(|(_, cumulative)| target < *cumulative)
+                    .unwrap_or(buckets.len() - 1);
+
+                let low = if index == 0 {
+                    1
+                } else {
+                    buckets[index - 1].0 + 1
+                };
+                let high = buckets[index].0.max(low);
+                rng.random_range(low..=high)
+            }
+        }
+    }
+}
+
+// "max_size,weight" 형식, #으로 시작하는 줄은 주석
+fn load_histogram(path: &Path) -> Result<Vec<(usize, f64)>, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    let mut buckets = vec![];
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let bucket = line.split_once(',').and_then(|(max, weight)| {
+            Some((max.trim().parse().ok()?, weight.trim().parse().ok()?))
+        });
+        match bucket {
+            Some(bucket) => buckets.push(bucket),
+            None => {
+                return Err(format!(
+                    "{}:{}: expected max_size,weight",
+                    path.display(),
+                    index + 1
+                ));
+            }
+        }
+    }
+
+    buckets.sort_by_key(|(max, _)| *max);
+    Ok(buckets)
+}
+
+// Box-Muller 변환
+fn standard_normal(rng: &mut StdRng) -> f64 {
+    let u1: f64 = 1.0 - rng.random::<f64>();
+    let u2: f64 = rng.random();
+
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+fn generate_value(payload: Payload, length: usize, rng: &mut StdRng) -> String {
+    match payload {
+        Payload::Random => (0..length)
+            .map(|_| ALPHANUMERIC[rng.random_range(0..ALPHANUMERIC.len())] as char)
+            .collect(),
+        Payload::Compressible => {
+            let mut value = String::with_capacity(length + 16);
+            while value.len() < length {
+                if !value.is_empty() {
+                    value.push(' ');
+                }
+                value.push_str(WORDS[rng.random_range(0..WORDS.len())]);
+            }
+            value.truncate(length);
+            value
+        }
+    }
+}
+
+// schema의 field마다 type에 맞는 값을 만든 JSON object
+fn generate_record(
+    cli: &Cli,
+    schema: &Schema,
+    value_size: &ValueSize,
+    index: u64,
+    rng: &mut StdRng,
+) -> serde_json::Value {
+    let mut object = serde_json::Map::new();
+
+    for field in &schema.fields {
+        let value = match field.kind {
+            FieldType::Int => json!(rng.random_range(0..1_000_000)),
+            FieldType::Float => json!((rng.random::<f64>() * 100_000.0).round() / 100.0),
+            FieldType::Bool => json!(rng.random::<bool>()),
+            // key와 같은 가상 시계 (row 순서대로 증가)
+            FieldType::Timestamp => {
+                json!(record::rfc3339(
+                    (EPOCH_MILLIS + index / KEYS_PER_MILLI) as i64
+                ))
+            }
+            FieldType::Text if field.tag => json!(format!(
+                "{}-{}",
+                field.name,
+                rng.random_range(0..cli.tag_cardinality.max(1))
+            )),
+            FieldType::Text => {
+                let length = value_size.sample(rng);
+                json!(generate_value(cli.payload, length, rng))
+            }
+            FieldType::Json => json!({
+                "id": rng.random_range(0..1_000_000),
+                "score": (rng.random::<f64>() * 10_000.0).round() / 100.0,
+                "labels": [
+                    WORDS[rng.random_range(0..WORDS.len())],
+                    WORDS[rng.random_range(0..WORDS.len())],
+                ],
+                "meta": {
+                    "source": WORDS[rng.random_range(0..WORDS.len())],
+                    "version": rng.random_range(1..10),
+                },
+            }),
+        };
+        object.insert(field.name.clone(), value);
+    }
+
+    serde_json::Value::Object(object)
+}
 
 fn main() {
-    let count = 100000;
+    let cli = Cli::parse();
+
+    let schema = cli.schema.as_ref().map(|path| {
+        Schema::load(path).unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        })
+    });
+    let output = cli.output.clone().unwrap_or_else(|| match schema {
+        Some(_) => PathBuf::from("dataset.jsonl"),
+        None => PathBuf::from("dataset.csv"),
+    });
+
+    let value_size = match ValueSize::from_cli(&cli) {
+        Ok(value_size) => value_size,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+    };
 
-    // length 20-500 random string
-    let mut rng = rand::rng();
+    let mut rng = StdRng::seed_from_u64(cli.seed);
+    let mut keys = KeyGenerator::new(cli.keys, cli.rows, cli.prefixes);
 
-    let mut lines = vec![];
+    let file = File::create(&output).expect("Failed to create output file");
+    let mut writer = BufWriter::new(file);
 
-    for _ in 0..count {
-        // 1000개마다 진행상황 출력
-        if lines.len() % 10000 == 0 {
-            println!("Generated {} lines", lines.len());
+    for index in 0..cli.rows {
+        // 100000개마다 진행상황 출력
+        if index % 100000 == 0 {
+            println!("Generated {} lines", index);
         }
 
-        // uuid v4
-        let key = uuid::Uuid::new_v4();
+        let key = keys.next(index, &mut rng);
 
-        // length 20-500 random string
-        let length = rng.random_range(20..=200);
-        let value = generate_random_string(length);
+        if let Some(schema) = &schema {
+            let value = generate_record(&cli, schema, &value_size, index, &mut rng);
+            writeln!(writer, "{}", json!({ "key": key, "value": value }))
+                .expect("Failed to write output file");
+            continue;
+        }
 
-        let line = format!("{},{}", key, value);
+        let length = value_size.sample(&mut rng);
+        let value = generate_value(cli.payload, length, &mut rng);
 
-        lines.push(line);
+        writeln!(writer, "{},{}", key, value).expect("Failed to write output file");
     }
 
-    let csv_file_text = lines.join("\n");
+    writer.flush().expect("Failed to write output file");
 
-    std::fs::write("dataset.csv", csv_file_text).unwrap();
+    println!(
+        "Wrote {} rows to {} (keys: {:?}, value size: {:?}, payload: {:?}, seed: {})",
+        cli.rows,
+        output.display(),
+        cli.keys,
+        cli.value_size,
+        cli.payload,
+        cli.seed
+    );
 }
 
-fn generate_random_string(length: usize) -> String {
-    use rand::Rng;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn keys(scheme: KeyScheme, rows: u64, prefixes: u64) -> Vec<String> {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut generator = KeyGenerator::new(scheme, rows, prefixes);
+
+        (0..rows)
+            .map(|index| generator.next(index, &mut rng))
+            .collect()
+    }
+
+    fn assert_unique(keys: &[String]) {
+        let unique: HashSet<&String> = keys.iter().collect();
+        assert_eq!(unique.len(), keys.len());
+    }
+
+    #[test]
+    fn every_scheme_generates_unique_keys() {
+        for scheme in KeyScheme::value_variants() {
+            assert_unique(&keys(*scheme, 50_000, 100));
+        }
+    }
+
+    #[test]
+    fn snowflake_sequence_keeps_keys_within_a_millisecond_unique() {
+        // 같은 ms 안의 key는 worker가 겹쳐도 sequence로 구분됨
+        let keys = keys(KeyScheme::Snowflake, KEYS_PER_MILLI * 3, 1);
+        assert_unique(&keys);
+
+        let sequences: Vec<u64> = keys[..KEYS_PER_MILLI as usize]
+            .iter()
+            .map(|key| key.parse::<u64>().unwrap() & 0xfff)
+            .collect();
+        assert_eq!(sequences, (0..KEYS_PER_MILLI).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn prefixed_keys_count_per_prefix() {
+        let keys = keys(KeyScheme::Prefixed, 1_000, 3);
+        assert_unique(&keys);
+
+        for prefix in ["p0/", "p1/", "p2/"] {
+            let counters: Vec<&str> = keys
+                .iter()
+                .filter_map(|key| key.strip_prefix(prefix))
+                .collect();
+            let expected: Vec<String> = (0..counters.len()).map(|i| format!("{:03}", i)).collect();
+            assert_eq!(counters, expected);
+        }
+    }
+
+    #[test]
+    fn prefixed_with_a_single_prefix_is_sequential() {
+        let keys = keys(KeyScheme::Prefixed, 10, 0);
 
-    let mut rng = rand::rng();
-    (0..length)
-        .map(|_| rng.random_range(b'a'..=b'z') as char)
-        .collect()
+        assert_eq!(keys.first().unwrap(), "p0/0");
+        assert_eq!(keys.last().unwrap(), "p0/9");
+        assert_unique(&keys);
+    }
 }
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+use crate::resource::format_bytes;
+use crate::runner::PhaseSummary;
+
+/// secondary index 수 하나에서 load phase를 실행한 결과
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IndexPoint {
+    pub indexes: usize,
+    pub record_count: usize,
+    pub duration_secs: f64,
+    pub rows_per_sec: f64,
+    pub p50_ms: Option<f64>,
+    pub p99_ms: Option<f64>,
+    pub fail_count: u64,
+    // load phase 전후의 크기 (backend가 보고하는 크기, 지원하지 않으면 data_dir 크기)
+    pub storage_bytes_before: Option<u64>,
+    pub storage_bytes_after: Option<u64>,
+    // 크기 증가량 / record 수
+    pub storage_bytes_per_record: Option<f64>,
+}
+
+impl IndexPoint {
+    /// load phase는 Write 또는 WriteBatch 하나만 실행함
+    pub fn new(
+        indexes: usize,
+        summary: &PhaseSummary,
+        record_count: usize,
+        storage_bytes_before: Option<u64>,
+        storage_bytes_after: Option<u64>,
+    ) -> Self {
+        let operation = summary.operations.values().next();
+        let latency = operation.and_then(|operation| operation.latency.as_ref());
+
+        let storage_bytes_per_record = match (storage_bytes_before, storage_bytes_after) {
+            (Some(before), Some(after)) if record_count > 0 => {
+                Some(after.saturating_sub(before) as f64 / record_count as f64)
+            }
+            _ => None,
+        };
+
+        IndexPoint {
+            indexes,
+            record_count,
+            duration_secs: summary.duration_secs,
+            rows_per_sec: operation.map_or(0.0, |operation| operation.row_throughput),
+            p50_ms: latency.map(|latency| latency.p50_ms),
+            p99_ms: latency.map(|latency| latency.p99_ms),
+            fail_count: operation.map_or(0, |operation| operation.fail_count),
+            storage_bytes_before,
+            storage_bytes_after,
+            storage_bytes_per_record,
+        }
+    }
+
+    pub fn storage_growth(&self) -> Option<u64> {
+        Some(
+            self.storage_bytes_after?
+                .saturating_sub(self.storage_bytes_before?),
+        )
+    }
+}
+
+/// --indexes 결과: index 수별 throughput과 크기 증가 (첫 번째 index 수가 기준)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IndexReport {
+    pub points: Vec<IndexPoint>,
+}
+
+impl IndexReport {
+    /// 기준 대비 마지막 index 수의 throughput 변화 (예: -0.35)
+    pub fn throughput_change(&self) -> Option<f64> {
+        let (first, last) = (self.points.first()?, self.points.last()?);
+        (first.rows_per_sec > 0.0).then(|| last.rows_per_sec / first.rows_per_sec - 1.0)
+    }
+
+    pub fn print(&self) {
+        let Some(baseline) = self.points.first() else {
+            return;
+        };
+        let format_ms = |value: Option<f64>| value.map_or("-".into(), |ms| format!("{:.3}", ms));
+
+        println!("@ Secondary indexes");
+        println!(
+            "| Indexes | rows/sec | vs {} | p50 (ms) | p99 (ms) | Storage growth | Bytes/record | Failed |",
+            baseline.indexes
+        );
+        println!("|---:|---:|---:|---:|---:|---:|---:|---:|");
+        for point in &self.points {
+            let ratio = if baseline.rows_per_sec > 0.0 {
+                format!("{:.2}x", point.rows_per_sec / baseline.rows_per_sec)
+            } else {
+                "-".into()
+            };
+
+            println!(
+                "| {} | {:.2} | {} | {} | {} | {} | {} | {} |",
+                point.indexes,
+                point.rows_per_sec,
+                ratio,
+                format_ms(point.p50_ms),
+                format_ms(point.p99_ms),
+                point.storage_growth().map_or("-".into(), format_bytes),
+                point
+                    .storage_bytes_per_record
+                    .map_or("-".into(), |bytes| format!("{:.1}", bytes)),
+                point.fail_count
+            );
+        }
+    }
+}
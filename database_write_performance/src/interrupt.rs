@@ -0,0 +1,85 @@
+use tokio::signal::unix::{SignalKind, signal};
+use tokio::sync::watch;
+use tokio::time::Instant;
+
+/// SIGINT/SIGTERM을 받았는지 알려주는 신호 (clone해서 여러 phase에 전달)
+///
+/// 첫 번째 signal은 진행 중인 phase를 멈추고 결과를 저장하게 하고,
+/// 두 번째 signal은 기다리지 않고 바로 종료한다.
+#[derive(Clone)]
+pub struct Interrupt {
+    receiver: watch::Receiver<bool>,
+}
+
+impl Interrupt {
+    pub fn listen() -> Self {
+        let (sender, receiver) = watch::channel(false);
+
+        tokio::spawn(async move {
+            let mut terminate = signal(SignalKind::terminate()).expect("Failed to listen SIGTERM");
+
+            for count in 1.. {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = terminate.recv() => {}
+                }
+
+                if count > 1 {
+                    eprintln!("Interrupted again, exiting without saving results");
+                    std::process::exit(130);
+                }
+
+                eprintln!("Interrupted, draining in-flight requests (interrupt again to exit now)");
+                let _ = sender.send(true);
+            }
+        });
+
+        Interrupt { receiver }
+    }
+
+    /// 이 interrupt를 받거나 deadline이 지나면 set되는 interrupt (--backend-timeout)
+    ///
+    /// 진행 중인 phase는 signal을 받았을 때와 같이 멈추고 그때까지의 결과를 남김
+    pub fn with_deadline(&self, deadline: Instant) -> Self {
+        let (sender, receiver) = watch::channel(self.is_set());
+        let mut parent = self.clone();
+
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = parent.wait() => {}
+                _ = tokio::time::sleep_until(deadline) => {
+                    eprintln!("Timed out, draining in-flight requests");
+                }
+                // 받는 쪽이 모두 drop되면 더 기다리지 않음
+                _ = sender.closed() => return,
+            }
+
+            let _ = sender.send(true);
+        });
+
+        Interrupt { receiver }
+    }
+
+    pub fn is_set(&self) -> bool {
+        *self.receiver.borrow()
+    }
+
+    /// future가 끝나기 전에 interrupt를 받으면 None (phase 밖의 connect, setup 등에 사용)
+    pub async fn race<T>(&self, future: impl Future<Output = T>) -> Option<T> {
+        let mut interrupt = self.clone();
+
+        tokio::select! {
+            biased;
+            _ = interrupt.wait() => None,
+            output = future => Some(output),
+        }
+    }
+
+    /// signal을 받을 때까지 대기 (이미 받았으면 바로 반환)
+    pub async fn wait(&mut self) {
+        // sender가 drop되면 더 이상 signal이 오지 않으므로 계속 대기
+        if self.receiver.wait_for(|set| *set).await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
+}
@@ -0,0 +1,21 @@
+// ydb client의 async builder가 깊게 중첩되어 기본 limit(128)을 넘음
+#![recursion_limit = "256"]
+
+pub mod comparison;
+pub mod config;
+pub mod dataset;
+pub mod db;
+pub mod distribution;
+pub mod indexes;
+pub mod interrupt;
+pub mod record;
+pub mod resource;
+pub mod result;
+pub mod retry;
+pub mod runner;
+pub mod stats;
+pub mod sweep;
+pub mod timeline;
+pub mod verify;
+pub mod workload;
+pub mod ycsb;
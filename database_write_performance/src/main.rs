@@ -1,170 +1,1034 @@
-use std::sync::atomic::AtomicU64;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
 
-pub mod db;
+use clap::{Parser, ValueEnum};
 
-#[derive(Clone)]
-struct WriteEntry {
-    key: String,
-    value: String,
+use database_write_performance::comparison;
+use database_write_performance::config::Config;
+use database_write_performance::dataset::{self, Format};
+use database_write_performance::db::{self, Database, Durability};
+use database_write_performance::indexes::{IndexPoint, IndexReport};
+use database_write_performance::interrupt::Interrupt;
+use database_write_performance::record::{Record, Schema};
+use database_write_performance::resource::{self, Sampler};
+use database_write_performance::result::{self, RunMetadata, RunResult};
+use database_write_performance::retry::{RetryPolicy, RetryStrategy};
+use database_write_performance::runner::{
+    self, Arrival, Operation, PhaseReport, Ramp, RateLimit, RunOptions,
+};
+use database_write_performance::sweep::{SweepPoint, SweepReport};
+use database_write_performance::timeline::{self, TimelineSample};
+use database_write_performance::workload::{Phase, WriteEntry, Written};
+use database_write_performance::{stats, verify};
+
+#[derive(Parser)]
+#[command(name = "Database Write Benchmark")]
+#[command(about = "Write throughput benchmark for databases", long_about = None)]
+struct Cli {
+    #[arg(
+        short,
+        long,
+        value_delimiter = ',',
+        required_unless_present_any = ["list", "all"],
+        conflicts_with = "all"
+    )]
+    /// Backends to run, comma separated (see --list)
+    backends: Vec<String>,
+
+    #[arg(long)]
+    /// Run every available database in sequence (the simulated "fake" backend is not included)
+    all: bool,
+
+    #[arg(long)]
+    /// Stop a backend after this many seconds (connect, setup and all phases); phases
+    /// finished by then are kept and the running phase is saved as partial results
+    backend_timeout: Option<u64>,
+
+    #[arg(long)]
+    /// Print the available backends and exit
+    list: bool,
+
+    #[arg(long)]
+    /// Backend connection settings file (defaults to benchmark.toml if present)
+    config: Option<PathBuf>,
+
+    #[arg(long, default_value = "dataset.csv")]
+    /// Dataset file: CSV, JSON Lines or Parquet, optionally .gz/.zst compressed (CSV/JSON Lines)
+    dataset: PathBuf,
+
+    #[arg(long, value_enum)]
+    /// Dataset format (detected from the file extension if omitted, CSV by default)
+    dataset_format: Option<Format>,
+
+    #[arg(long, conflicts_with_all = ["verify", "phases"])]
+    /// Schema file: load each value as a record with typed fields (a JSON object)
+    /// and store the fields as native columns, documents or tags
+    schema: Option<PathBuf>,
+
+    #[arg(long, default_value = "1000000")]
+    /// Maximum number of records to load from the dataset
+    records: usize,
+
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    /// Number of concurrent requests (defaults to each backend's worker_count)
+    concurrency: Option<u64>,
+
+    #[arg(long, value_delimiter = ',', conflicts_with = "concurrency", value_parser = clap::value_parser!(u64).range(1..))]
+    /// Run the load phase at each of these concurrency levels (e.g. 1,4,16,64,256,1024,4096,10000),
+    /// report the saturation point, then run the remaining phases at the fastest level
+    sweep: Vec<u64>,
+
+    #[arg(long, default_value = "1", value_parser = clap::value_parser!(u64).range(1..))]
+    /// Rows per write request in the load phase (1 = single-row writes)
+    batch_size: u64,
+
+    #[arg(long, value_enum)]
+    /// Durability every backend must provide before acknowledging a write
+    /// (backends that cannot provide it are skipped; defaults to each backend's own setting).
+    /// Server-wide settings it changes (MySQL/MariaDB flush settings, the CockroachDB cluster
+    /// setting, RESP CONFIG SET) are restored after each backend run
+    durability: Option<Durability>,
+
+    #[arg(long, value_delimiter = ',', default_value = "0")]
+    /// Secondary indexes to create at setup; with several counts (e.g. 0,3,6) the load phase runs
+    /// once per count and reports throughput and storage growth, keeping the last count afterwards
+    indexes: Vec<usize>,
+
+    #[arg(long, value_parser = parse_rate)]
+    /// Open-loop mode: send requests at this rate (requests/sec) regardless of responses
+    target_rate: Option<f64>,
+
+    #[arg(long, value_enum, default_value_t = Arrival::Fixed, requires = "target_rate")]
+    /// Request arrival pattern in open-loop mode
+    arrival: Arrival,
+
+    #[arg(long)]
+    /// Stop sending requests in each phase after this many seconds
+    duration: Option<u64>,
+
+    #[arg(long)]
+    /// Stop the load phase after writing this many records
+    max_records: Option<usize>,
+
+    #[arg(long, default_value = "10")]
+    /// Seconds to wait for in-flight requests after SIGINT/SIGTERM before saving partial results
+    grace_period: u64,
+
+    #[arg(long, default_value = "0")]
+    /// Seconds at the start of each phase whose requests are excluded from the results
+    warmup: u64,
+
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..100))]
+    /// Ramp concurrency up in steps of this percent of the target, after the warm-up
+    ramp_step: Option<u64>,
+
+    #[arg(long, default_value = "10", value_parser = clap::value_parser!(u64).range(1..))]
+    /// Seconds per ramp step
+    ramp_interval: u64,
+
+    #[arg(long, value_enum, default_value_t = RetryStrategy::Fixed)]
+    /// Retry strategy for failed requests
+    retry: RetryStrategy,
+
+    #[arg(long, default_value = "10")]
+    /// Maximum attempts per request, including the first (fixed and exponential)
+    retry_count: usize,
+
+    #[arg(long, default_value = "100")]
+    /// Delay between attempts (fixed) or initial backoff (exponential, deadline) in milliseconds
+    retry_delay_ms: u64,
+
+    #[arg(long, default_value = "5000")]
+    /// Maximum backoff in milliseconds (exponential, deadline)
+    retry_max_delay_ms: u64,
+
+    #[arg(long, default_value = "30000")]
+    /// Give up on a request this many milliseconds after its first attempt (deadline)
+    retry_deadline_ms: u64,
+
+    #[arg(long)]
+    /// After the load phase, read back every key and report missing, stale and corrupted values
+    verify: bool,
+
+    #[arg(long, value_delimiter = ',', value_parser = parse_phase)]
+    /// Phases run after the load phase: read percentages or YCSB workloads (e.g. 100,95,ycsb-a,ycsb-b:uniform)
+    phases: Vec<Phase>,
+
+    #[arg(long)]
+    /// Operations per phase after the load phase (defaults to the number of loaded records)
+    operations: Option<usize>,
+
+    #[arg(long)]
+    /// Write per-second throughput/latency samples to this file (.json for JSON, CSV otherwise)
+    timeline: Option<PathBuf>,
+
+    #[arg(long, default_value = "results")]
+    /// Directory to write one JSON result file per backend into (see the report binary)
+    results_dir: PathBuf,
+
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    /// Result output format
+    output: OutputFormat,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+struct BackendRun {
+    result: RunResult,
+    timeline: Vec<TimelineSample>,
+    // --backend-timeout으로 멈춰서 result가 일부만 있음
+    timed_out: bool,
+}
+
+// 실행하지 못한 backend와 그 이유
+struct Skipped {
+    backend: String,
+    reason: String,
+}
+
+fn parse_phase(text: &str) -> Result<Phase, String> {
+    Phase::parse(text).ok_or_else(|| format!("invalid phase: {}", text))
+}
+
+fn parse_rate(text: &str) -> Result<f64, String> {
+    match text.parse::<f64>() {
+        Ok(rate) if rate.is_finite() && rate > 0.0 => Ok(rate),
+        _ => Err(format!("invalid rate: {}", text)),
+    }
 }
 
 #[tokio::main]
 async fn main() {
-    // parse args
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() != 2 {
-        eprintln!("Usage: {} <db_type>", args[0]);
-        eprintln!(
-            "db_type: postgres, rrdb, mysql, mariadb, mongodb, scylla, cassandra, influxdb_v2, influxdb_v3, timescaledb, couchdb, yugabytedb, cockroachdb, clickhouse, elasticsearch, opensearch, etcd, nats, ydb, tidb, tikv, barus, fake"
+    let cli = Cli::parse();
+
+    if cli.list {
+        for db_type in db::DATABASE_TYPES.iter().chain(db::SIMULATED_TYPES) {
+            println!("{}", db_type);
+        }
+        return;
+    }
+
+    let config = Config::load(cli.config.as_deref()).unwrap_or_else(|error| {
+        eprintln!("Failed to load config: {:?}", error);
+        std::process::exit(1);
+    });
+
+    let schema = cli.schema.as_ref().map(|path| {
+        let schema = Schema::load(path).unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        });
+        Arc::new(schema)
+    });
+    let rows = Rows {
+        path: cli.dataset.clone(),
+        format: cli.dataset_format,
+        limit: cli.records.min(cli.max_records.unwrap_or(usize::MAX)),
+        schema,
+    };
+    if let Err(error) = rows.check() {
+        eprintln!("Failed to read {}: {}", cli.dataset.display(), error);
+        std::process::exit(1);
+    }
+    let dataset_sha256 = result::dataset_sha256(&cli.dataset).expect("Failed to hash dataset");
+
+    let backends: Vec<String> = if cli.all {
+        db::DATABASE_TYPES
+            .iter()
+            .map(|db_type| db_type.to_string())
+            .collect()
+    } else {
+        cli.backends.clone()
+    };
+
+    let mut runs = vec![];
+    let mut skipped = vec![];
+    let interrupt = Interrupt::listen();
+
+    for backend in &backends {
+        // interrupt를 받으면 남은 backend는 실행하지 않음
+        if interrupt.is_set() {
+            skipped.push(Skipped {
+                backend: backend.clone(),
+                reason: "Interrupted".into(),
+            });
+            continue;
+        }
+
+        // timeout은 backend 전용 interrupt로 전달해서 끝난 phase의 결과는 남김
+        let deadline = cli
+            .backend_timeout
+            .map(|seconds| tokio::time::Instant::now() + Duration::from_secs(seconds));
+        let backend_interrupt = match deadline {
+            Some(deadline) => interrupt.with_deadline(deadline),
+            None => interrupt.clone(),
+        };
+
+        let run = run_backend(
+            &cli,
+            &config,
+            backend,
+            rows.clone(),
+            &dataset_sha256,
+            &backend_interrupt,
+        )
+        .await;
+
+        // 연결/setup 실패나 timeout이 나도 다음 backend로 진행
+        let timed_out = !interrupt.is_set()
+            && deadline.is_some_and(|deadline| tokio::time::Instant::now() >= deadline);
+        let run = match run {
+            Err(reason) if timed_out => Err(format!(
+                "Timed out after {} s ({})",
+                cli.backend_timeout.unwrap_or_default(),
+                reason
+            )),
+            run => run,
+        };
+
+        match run {
+            Ok(mut run) => {
+                run.timed_out = timed_out;
+                if timed_out {
+                    eprintln!(
+                        "{} timed out after {} s, saving partial results",
+                        backend,
+                        cli.backend_timeout.unwrap_or_default()
+                    );
+                }
+
+                let path = run
+                    .result
+                    .save(&cli.results_dir)
+                    .expect("Failed to write result file");
+                eprintln!("Result written to {}", path.display());
+
+                runs.push(run);
+            }
+            Err(reason) => {
+                eprintln!("Skipping {}: {}", backend, reason);
+                skipped.push(Skipped {
+                    backend: backend.clone(),
+                    reason,
+                });
+            }
+        }
+    }
+
+    if let Some(path) = &cli.timeline {
+        let samples: Vec<TimelineSample> = runs
+            .iter()
+            .flat_map(|run| run.timeline.iter().cloned())
+            .collect();
+
+        timeline::write(path, &samples).expect("Failed to write timeline");
+    }
+
+    if cli.output == OutputFormat::Json {
+        let results: Vec<&RunResult> = runs.iter().map(|run| &run.result).collect();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&results).expect("Failed to serialize results")
         );
+    }
+
+    if backends.len() > 1 {
+        print_suite_summary(&cli, &runs, &skipped);
+    }
+
+    if runs.is_empty() {
         std::process::exit(1);
     }
+}
 
-    let db_arg = &args[1];
-    println!("Using database: {}", db_arg);
+/// 여러 backend를 실행했을 때 성공/실패 목록과 load phase 비교 표를 출력
+fn print_suite_summary(cli: &Cli, runs: &[BackendRun], skipped: &[Skipped]) {
+    let mut summary = String::new();
 
-    let db = db::new_database(db_arg)
-        .await
-        .expect("Failed to create database");
+    summary.push_str(&format!(
+        "@ Suite summary: {} completed, {} skipped\n",
+        runs.len(),
+        skipped.len()
+    ));
+    for skipped in skipped {
+        summary.push_str(&format!(
+            "@ Skipped {}: {}\n",
+            skipped.backend, skipped.reason
+        ));
+    }
 
-    db.ping().await.expect("Failed to ping database");
+    for run in runs {
+        if run.timed_out {
+            summary.push_str(&format!(
+                "@ Timed out {}: partial results saved\n",
+                run.result.metadata.backend
+            ));
+        }
 
-    db.setup().await.expect("Failed to setup database");
+        if let Some(sweep) = &run.result.sweep {
+            summary.push_str(&format!(
+                "@ Sweep {}: best concurrency {} ({:.2} rows/sec), saturation point {}\n",
+                run.result.metadata.backend,
+                sweep.best_concurrency,
+                sweep.best().map_or(0.0, |point| point.rows_per_sec),
+                sweep.saturation_concurrency
+            ));
+        }
 
-    let csv_text = std::fs::read_to_string("dataset.csv").unwrap();
+        if let Some(indexes) = &run.result.indexes
+            && let (Some(first), Some(last)) = (indexes.points.first(), indexes.points.last())
+        {
+            summary.push_str(&format!(
+                "@ Indexes {}: {:.2} rows/sec with {}, {:.2} rows/sec with {} ({:+.1}%)\n",
+                run.result.metadata.backend,
+                first.rows_per_sec,
+                first.indexes,
+                last.rows_per_sec,
+                last.indexes,
+                indexes.throughput_change().unwrap_or_default() * 100.0
+            ));
+        }
+    }
 
-    let worker_count = db.worker_count();
+    // results_dir의 이전 결과도 함께 읽어서 직전 실행 대비 변화를 표시
+    let completed: Vec<&str> = runs
+        .iter()
+        .map(|run| run.result.metadata.backend.as_str())
+        .collect();
+    let results: Vec<RunResult> = RunResult::load_dir(&cli.results_dir)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|result| completed.contains(&result.metadata.backend.as_str()))
+        .collect();
 
-    let (sender, mut receiver) = tokio::sync::mpsc::channel::<WriteEntry>(worker_count);
+    if let Some(table) = comparison::render(&results, "load", None) {
+        summary.push('\n');
+        summary.push_str(&table);
+    }
+
+    // JSON 출력일 때는 stdout을 JSON 전용으로 유지
+    if cli.output == OutputFormat::Json {
+        eprint!("{}", summary);
+    } else {
+        println!();
+        print!("{}", summary);
+    }
+}
+
+// 처음 몇 개만 출력하고 나머지는 개수만 보고
+const MALFORMED_REPORT_LIMIT: u64 = 10;
+
+/// load phase에서 쓰는 dataset. load phase를 실행할 때마다 파일을 처음부터 다시 읽음
+///
+/// dataset 전체를 메모리에 올리지 않도록 row는 읽는 대로 요청으로 만들어 보냄
+#[derive(Clone)]
+struct Rows {
+    path: PathBuf,
+    format: Option<Format>,
+    // 읽을 최대 row 수 (--records, --max-records 중 작은 값)
+    limit: usize,
+    // --schema가 있으면 value를 typed record로 나눔
+    schema: Option<Arc<Schema>>,
+}
+
+enum Row {
+    Entry(WriteEntry),
+    Record(Record),
+}
+
+impl Rows {
+    /// 읽을 수 없거나 schema에 맞지 않는 row는 건너뜀 (check에서 이미 보고함)
+    fn open(&self) -> std::io::Result<Box<dyn Iterator<Item = Row> + Send>> {
+        let schema = self.schema.clone();
+        let rows = dataset::open(&self.path, self.format)?
+            .filter_map(Result::ok)
+            .filter_map(move |entry| match &schema {
+                Some(schema) => schema.parse(entry.key, &entry.value).ok().map(Row::Record),
+                None => Some(Row::Entry(entry)),
+            })
+            .take(self.limit);
+
+        Ok(Box::new(rows))
+    }
+
+    /// 실행 전에 처음 limit개 row까지 한 번 읽어서 읽을 수 없는 row를 보고함
+    fn check(&self) -> std::io::Result<()> {
+        let mut count = 0;
+        let mut malformed_count = 0;
+        let mut mismatched_count = 0;
 
-    // producer
-    tokio::spawn(async move {
-        for (i, line) in csv_text.lines().enumerate() {
-            if i >= 1000000 {
+        for record in dataset::open(&self.path, self.format)? {
+            if count >= self.limit {
                 break;
             }
 
-            if i % 10000 == 0 {
-                println!("Writing {} lines", i);
+            let entry = match record {
+                Ok(entry) => entry,
+                Err(malformed) => {
+                    malformed_count += 1;
+                    if malformed_count <= MALFORMED_REPORT_LIMIT {
+                        eprintln!(
+                            "Skipping malformed record at line {}: {}",
+                            malformed.line, malformed.reason
+                        );
+                    }
+                    continue;
+                }
+            };
+
+            match &self.schema {
+                Some(schema) => match schema.parse(entry.key, &entry.value) {
+                    Ok(_) => count += 1,
+                    Err(reason) => {
+                        mismatched_count += 1;
+                        if mismatched_count <= MALFORMED_REPORT_LIMIT {
+                            eprintln!("Skipping record that does not match the schema: {}", reason);
+                        }
+                    }
+                },
+                None => count += 1,
             }
+        }
 
-            let mut parts = line.splitn(2, ',');
-            let key = parts.next().unwrap();
-            let value = parts.next().unwrap();
+        if malformed_count > 0 {
+            eprintln!(
+                "Skipped {} malformed records in {}",
+                malformed_count,
+                self.path.display()
+            );
+        }
+        if mismatched_count > 0 {
+            eprintln!(
+                "Skipped {} records that do not match the schema",
+                mismatched_count
+            );
+        }
 
-            let entry = WriteEntry {
-                key: key.to_string(),
-                value: value.to_string(),
+        match count {
+            0 if mismatched_count > 0 => Err(std::io::Error::other("no records match the schema")),
+            0 => Err(std::io::Error::other("no records")),
+            _ => Ok(()),
+        }
+    }
+
+    /// 읽은 row로 요청 하나를 만듦 (batch_size가 1이면 기존처럼 단건 write, rows는 비어 있지 않음)
+    fn operation(&self, rows: Vec<Row>, batch_size: usize) -> Operation {
+        let mut entries = vec![];
+        let mut records = vec![];
+        for row in rows {
+            match row {
+                Row::Entry(entry) => entries.push((entry.key, entry.value)),
+                Row::Record(record) => records.push(record),
+            }
+        }
+
+        match (&self.schema, batch_size) {
+            (None, 1) => {
+                let (key, value) = entries.remove(0);
+                Operation::Write { key, value }
+            }
+            (None, _) => Operation::WriteBatch { entries },
+            (Some(schema), 1) => Operation::WriteRecord {
+                schema: schema.clone(),
+                record: records.remove(0),
+            },
+            (Some(schema), _) => Operation::WriteRecords {
+                schema: schema.clone(),
+                records,
+            },
+        }
+    }
+}
+
+/// setup 후 --schema가 있으면 native column 등을 준비하고 secondary index를 만듦
+async fn setup(
+    db: &(dyn Database + Send + Sync),
+    cli: &Cli,
+    rows: &Rows,
+    indexes: usize,
+) -> Result<(), String> {
+    db.setup(cli.durability)
+        .await
+        .map_err(|error| format!("Failed to setup database: {:?}", error))?;
+
+    let schema = rows.schema.as_deref();
+    if let Some(schema) = schema {
+        db.setup_schema(schema)
+            .await
+            .map_err(|error| format!("Failed to setup schema: {:?}", error))?;
+    }
+
+    let field_count = cli.indexes.iter().copied().max().unwrap_or(0);
+    db.create_indexes(indexes, field_count, schema)
+        .await
+        .map_err(|error| format!("Failed to create {} indexes: {:?}", indexes, error))?;
+
+    Ok(())
+}
+
+/// --indexes에서 재는 크기 (backend가 보고하는 크기, 지원하지 않으면 data_dir 크기)
+async fn storage_size(db: &(dyn Database + Send + Sync), data_dir: Option<&Path>) -> Option<u64> {
+    match (db.storage_size().await, data_dir) {
+        (Ok(size), _) => Some(size),
+        (Err(_), Some(data_dir)) => resource::directory_size(data_dir).await,
+        (Err(_), None) => None,
+    }
+}
+
+async fn run_backend(
+    cli: &Cli,
+    config: &Config,
+    db_arg: &str,
+    rows: Rows,
+    dataset_sha256: &str,
+    interrupt: &Interrupt,
+) -> Result<BackendRun, String> {
+    let started_at = result::timestamp();
+
+    let text_output = cli.output == OutputFormat::Text;
+    if text_output {
+        println!("Using database: {}", db_arg);
+    } else {
+        eprintln!("Using database: {}", db_arg);
+    }
+
+    // connect와 첫 setup은 phase 밖이므로 interrupt(timeout 포함)와 경쟁시킴
+    let db = interrupt
+        .race(db::new_database(db_arg, config))
+        .await
+        .ok_or("Interrupted while connecting")?
+        .map_err(|error| format!("Failed to create database: {:?}", error))?;
+
+    interrupt
+        .race(db.ping())
+        .await
+        .ok_or("Interrupted while connecting")?
+        .map_err(|error| format!("Failed to ping database: {:?}", error))?;
+
+    // 실패, interrupt, timeout으로 끝나도 setup이 바꾼 서버 전체 설정은 되돌림
+    let run: Result<BackendRun, String> = async {
+        // 이후 phase는 마지막 index 수로 실행
+        let indexes = cli.indexes.last().copied().unwrap_or(0);
+        interrupt
+            .race(setup(db.as_ref(), cli, &rows, indexes))
+            .await
+            .ok_or("Interrupted during setup")??;
+
+        let target = resource::Target::resolve(&config.backend(db_arg))
+            .map_err(|error| format!("Failed to resolve resource target: {}", error))?;
+        let data_dir = target.data_dir.clone();
+
+        let mut options = RunOptions {
+            worker_count: cli
+                .concurrency
+                .map_or_else(|| db.worker_count(), |concurrency| concurrency as usize),
+            retry: RetryPolicy {
+                strategy: cli.retry,
+                max_attempts: cli.retry_count,
+                delay: Duration::from_millis(cli.retry_delay_ms),
+                max_delay: Duration::from_millis(cli.retry_max_delay_ms),
+                deadline: Duration::from_millis(cli.retry_deadline_ms),
+            },
+            rate: cli.target_rate.map(|requests_per_sec| RateLimit {
+                requests_per_sec,
+                arrival: cli.arrival,
+            }),
+            warmup: Duration::from_secs(cli.warmup),
+            ramp: cli.ramp_step.map(|step_percent| Ramp {
+                step_percent: step_percent as usize,
+                interval: Duration::from_secs(cli.ramp_interval),
+            }),
+            duration: cli.duration.map(Duration::from_secs),
+            grace: Duration::from_secs(cli.grace_period),
+        };
+
+        let mut phases = vec![];
+        let mut timeline = vec![];
+
+        let batch_size = cli.batch_size as usize;
+
+        // concurrency마다 setup부터 다시 해서 load phase를 실행하고, 가장 빠른 concurrency로 이후를 진행
+        let sweep = if cli.sweep.is_empty() {
+            None
+        } else {
+            let mut points = vec![];
+
+            for &concurrency in &cli.sweep {
+                let concurrency = concurrency as usize;
+                // interrupt를 받으면 남은 concurrency를 건너뛰고 그때까지의 결과로 report
+                if interrupt
+                    .race(setup(db.as_ref(), cli, &rows, indexes))
+                    .await
+                    .transpose()?
+                    .is_none()
+                {
+                    break;
+                }
+
+                let level_options = RunOptions {
+                    worker_count: concurrency,
+                    ..options
+                };
+                let LoadReport { report, .. } = run_load(
+                    db.clone(),
+                    level_options,
+                    interrupt,
+                    rows.clone(),
+                    batch_size,
+                    false,
+                )
+                .await;
+
+                let name = format!("sweep-{}", concurrency);
+                let point = SweepPoint::from_summary(concurrency, &report.summary(&name));
+                if text_output {
+                    println!(
+                        "@ Sweep concurrency {}: {:.2} rows/sec, p99 {} ms",
+                        concurrency,
+                        point.rows_per_sec,
+                        point.p99_ms.map_or("-".into(), |ms| format!("{:.3}", ms))
+                    );
+                }
+                timeline.extend(label_timeline(&report.timeline, db_arg, &name));
+                points.push(point);
+            }
+
+            // 첫 concurrency를 끝내기 전에 interrupt를 받으면 sweep 결과 없이 report
+            let report = SweepReport::new(points);
+            if let Some(report) = &report {
+                if text_output {
+                    report.print();
+                }
+
+                interrupt
+                    .race(setup(db.as_ref(), cli, &rows, indexes))
+                    .await
+                    .transpose()?;
+                options.worker_count = report.best_concurrency;
+            }
+
+            report
+        };
+
+        // index 수를 여러 개 지정하면 마지막을 제외한 index 수마다 setup부터 다시 해서 load phase를 실행
+        let compare_indexes = cli.indexes.len() > 1;
+        let mut index_points = vec![];
+        if compare_indexes {
+            for &count in &cli.indexes[..cli.indexes.len() - 1] {
+                if interrupt
+                    .race(setup(db.as_ref(), cli, &rows, count))
+                    .await
+                    .transpose()?
+                    .is_none()
+                {
+                    break;
+                }
+
+                let before = storage_size(db.as_ref(), data_dir.as_deref()).await;
+                let LoadReport {
+                    report, written, ..
+                } = run_load(
+                    db.clone(),
+                    options,
+                    interrupt,
+                    rows.clone(),
+                    batch_size,
+                    false,
+                )
+                .await;
+                let after = storage_size(db.as_ref(), data_dir.as_deref()).await;
+
+                let name = format!("indexes-{}", count);
+                let point = IndexPoint::new(count, &report.summary(&name), written, before, after);
+                if text_output {
+                    println!(
+                        "@ {} secondary indexes: {:.2} rows/sec",
+                        count, point.rows_per_sec
+                    );
+                }
+                timeline.extend(label_timeline(&report.timeline, db_arg, &name));
+                index_points.push(point);
+            }
+
+            interrupt
+                .race(setup(db.as_ref(), cli, &rows, indexes))
+                .await
+                .transpose()?;
+        }
+
+        // load phase (sweep 중에 interrupt를 받았으면 건너뜀)
+        let mut written = 0;
+        let mut written_keys = Written::default();
+        let mut resources = None;
+        if !interrupt.is_set() {
+            let before = if compare_indexes {
+                storage_size(db.as_ref(), data_dir.as_deref()).await
+            } else {
+                None
+            };
+
+            // resource는 이 load phase 동안만 재서 record당 값이 sweep이나 다른 index 수의 load를 포함하지 않게 함
+            let sampler = if target.is_empty() {
+                None
+            } else {
+                Some(Sampler::start(target).await)
             };
 
-            sender.send(entry).await.expect("Failed to send entry");
+            // verify와 이후 phase가 있을 때만 쓴 key를 모아 둠
+            let collect_written = cli.verify || !cli.phases.is_empty();
+            let load = run_load(
+                db.clone(),
+                options,
+                interrupt,
+                rows.clone(),
+                batch_size,
+                collect_written,
+            )
+            .await;
+            let report = load.report;
+            written = load.written;
+            written_keys = load.keys;
+            if let Some(sampler) = sampler {
+                resources = Some(sampler.finish(written).await);
+            }
+            if text_output {
+                print_load_report(&report, batch_size);
+                if let Some(usage) = &resources {
+                    usage.print();
+                }
+            }
+            timeline.extend(label_timeline(&report.timeline, db_arg, "load"));
+            let summary = report.summary("load");
+
+            if compare_indexes {
+                let after = storage_size(db.as_ref(), data_dir.as_deref()).await;
+                index_points.push(IndexPoint::new(indexes, &summary, written, before, after));
+            }
+            phases.push(summary);
         }
-    });
 
-    let retry_count = 10;
-    let retry_delay_ms = 100;
-
-    let _fail_count = std::sync::Arc::new(AtomicU64::new(0));
-    let _success_count = std::sync::Arc::new(AtomicU64::new(0));
-    let _max_latency_ms = std::sync::Arc::new(AtomicU64::new(0));
-    let _min_latency_ms = std::sync::Arc::new(AtomicU64::new(u64::MAX));
-    let _total_latency_ms = std::sync::Arc::new(AtomicU64::new(0));
-
-    // consumer
-    let fail_count = _fail_count.clone();
-    let success_count = _success_count.clone();
-    let max_latency_ms = _max_latency_ms.clone();
-    let min_latency_ms = _min_latency_ms.clone();
-    let total_latency_ms = _total_latency_ms.clone();
-
-    let start = std::time::Instant::now();
-
-    tokio::spawn(async move {
-        let request_count = std::sync::Arc::new(AtomicU64::new(0));
-        let done_count = std::sync::Arc::new(AtomicU64::new(0));
-        let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(worker_count));
-
-        while let Some(entry) = receiver.recv().await {
-            // recv 후 바로 세마포어 획득 - 이 지점에서 블록됨
-            let permit = semaphore.clone().acquire_owned().await.unwrap();
-
-            request_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
-
-            let db = db.clone();
-            let done_count = done_count.clone();
-            let fail_count = fail_count.clone();
-            let max_latency_ms = max_latency_ms.clone();
-            let min_latency_ms = min_latency_ms.clone();
-            let total_latency_ms = total_latency_ms.clone();
-            let success_count = success_count.clone();
-
-            tokio::spawn(async move {
-                // permit을 spawn 내부로 이동
-                let _permit = permit;
-
-                for _ in 0..retry_count {
-                    let write_start = std::time::Instant::now();
-
-                    match db.write(&entry.key, &entry.value).await {
-                        Ok(_) => {
-                            let write_duration = write_start.elapsed();
-                            max_latency_ms.fetch_max(
-                                write_duration.as_millis() as u64,
-                                std::sync::atomic::Ordering::SeqCst,
-                            );
-                            min_latency_ms.fetch_min(
-                                write_duration.as_millis() as u64,
-                                std::sync::atomic::Ordering::SeqCst,
-                            );
-                            total_latency_ms.fetch_add(
-                                write_duration.as_millis() as u64,
-                                std::sync::atomic::Ordering::SeqCst,
-                            );
-                            success_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
-
-                            break;
-                        }
-                        Err(_e) => {
-                            // eprintln!("Write error: {:?}, retrying...", e);
-                            tokio::time::sleep(std::time::Duration::from_millis(retry_delay_ms))
-                                .await;
-                            fail_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
-                        }
-                    }
+        let index_report = compare_indexes.then_some(IndexReport {
+            points: index_points,
+        });
+        if text_output && let Some(report) = &index_report {
+            report.print();
+        }
+
+        // verify와 이후 phase는 key/value dataset에서만 실행 (--schema와 같이 쓸 수 없음)
+        let written_keys = Arc::new(written_keys);
+
+        // 다른 phase의 update가 값을 바꾸기 전에 검증
+        let verify = if cli.verify && !interrupt.is_set() {
+            if text_output {
+                println!("@ Running verify");
+            }
+
+            let report = verify::run(
+                db.clone(),
+                &written_keys.keys,
+                options.worker_count,
+                options.retry,
+                interrupt,
+            )
+            .await;
+            if text_output {
+                report.print();
+            }
+            Some(report)
+        } else {
+            None
+        };
+
+        // read-only / mixed / YCSB phase (load phase에서 쓴 key만 사용)
+        let operation_count = cli.operations.unwrap_or(written_keys.keys.len());
+
+        for phase in &cli.phases {
+            if interrupt.is_set() || written_keys.is_empty() {
+                break;
+            }
+
+            if text_output {
+                println!("@ Running phase: {}", phase.name());
+            }
+
+            // scan을 지원하지 않는 DB는 건너뜀
+            if phase.uses_scan()
+                && let Err(db::Errors::Unsupported(operation)) =
+                    db.scan(&written_keys.keys[0].0, 1).await
+            {
+                if text_output {
+                    println!("@ Skipped: {} is not supported", operation);
                 }
+                continue;
+            }
 
-                done_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
-                // _permit이 drop되면서 자동으로 세마포어 해제
-            });
+            let (sender, receiver) = tokio::sync::mpsc::channel::<Operation>(options.worker_count);
+            phase.spawn_producer(written_keys.clone(), operation_count, sender);
+
+            let report = runner::run_phase(db.clone(), options, interrupt, receiver).await;
+            if text_output {
+                report.print();
+            }
+            timeline.extend(label_timeline(&report.timeline, db_arg, &phase.name()));
+            phases.push(report.summary(&phase.name()));
         }
-        let request_count = request_count.load(std::sync::atomic::Ordering::SeqCst);
-        println!("@ All requests sent: {}", request_count);
 
-        // 모든 작업이 끝날 때까지 대기
-        while done_count.load(std::sync::atomic::Ordering::SeqCst) < request_count {
-            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        let metadata = RunMetadata {
+            backend: db_arg.to_string(),
+            record_count: written,
+            concurrency: options.worker_count,
+            batch_size,
+            target_rate: cli.target_rate,
+            durability: cli.durability,
+            indexes,
+            dataset: cli.dataset.display().to_string(),
+            dataset_sha256: dataset_sha256.to_string(),
+            git_revision: result::git_revision(),
+            timestamp: started_at,
+        };
+
+        Ok(BackendRun {
+            result: RunResult {
+                metadata,
+                phases,
+                verify,
+                resources,
+                sweep,
+                indexes: index_report,
+                partial: interrupt.is_set(),
+            },
+            timeline,
+            timed_out: false,
+        })
+    }
+    .await;
+
+    if let Err(error) = db.restore_settings().await {
+        eprintln!("Failed to restore {} settings: {:?}", db_arg, error);
+    }
+
+    run
+}
+
+/// load phase 결과와 실제로 쓴 row
+struct LoadReport {
+    report: PhaseReport,
+    // 성공한 요청의 row 수 (duration, max_records, interrupt로 멈추면 앞쪽 일부만)
+    written: usize,
+    // collect_written이면 쓴 key (verify와 이후 phase에서 사용)
+    keys: Written,
+}
+
+/// dataset을 처음부터 읽으면서 batch_size씩 write하는 load phase
+async fn run_load(
+    db: Arc<dyn Database + Send + Sync>,
+    options: RunOptions,
+    interrupt: &Interrupt,
+    rows: Rows,
+    batch_size: usize,
+    collect_written: bool,
+) -> LoadReport {
+    let (sender, receiver) = tokio::sync::mpsc::channel::<Operation>(options.worker_count);
+
+    // producer: 파일 읽기와 압축 해제가 runtime worker를 막지 않도록 blocking thread에서 실행
+    let producer = tokio::task::spawn_blocking(move || {
+        let mut keys = Written::default();
+        let mut sent = 0;
+
+        let mut stream = match rows.open() {
+            Ok(stream) => stream,
+            Err(error) => {
+                eprintln!("Failed to read {}: {}", rows.path.display(), error);
+                return keys;
+            }
+        };
+
+        loop {
+            let batch: Vec<Row> = stream.by_ref().take(batch_size).collect();
+            if batch.is_empty() {
+                break;
+            }
+
+            if sent % 10000 < batch_size {
+                eprintln!("Writing {} lines", sent);
+            }
+
+            if collect_written {
+                for row in &batch {
+                    if let Row::Entry(entry) = row {
+                        keys.push(entry);
+                    }
+                }
+            }
+
+            let count = batch.len();
+            let operation = rows.operation(batch, batch_size);
+
+            // timeout으로 phase가 중단되면 receiver가 drop됨
+            if sender.blocking_send(operation).is_err() {
+                break;
+            }
+            sent += count;
         }
-    })
-    .await
-    .unwrap();
 
-    let duration = start.elapsed();
+        keys
+    });
+
+    let report = runner::run_phase(db, options, interrupt, receiver).await;
+    let mut keys = producer.await.expect("Dataset reader panicked");
+
+    // 보낸 요청이 아니라 성공한 요청의 row만 셈 (실패한 batch는 record_count와 verify에서 제외)
+    let written = report.written_rows() as usize;
+    keys.keys.truncate(written);
+
+    LoadReport {
+        report,
+        written,
+        keys,
+    }
+}
 
-    println!("@ All writes completed in {:?}", duration);
+fn print_load_report(report: &PhaseReport, batch_size: usize) {
+    let (name, write_stats) = if batch_size == 1 {
+        ("Write", &report.stats.writes)
+    } else {
+        ("WriteBatch", &report.stats.write_batches)
+    };
+
+    println!("@ All requests sent: {}", report.request_count);
+    println!("@ All writes completed in {:?}", report.duration);
+    report.print_interrupted();
+    report.print_rate();
+    report.print_stages();
     println!(
         "@ Fail count: {}, Success count: {}",
-        _fail_count.load(std::sync::atomic::Ordering::SeqCst),
-        _success_count.load(std::sync::atomic::Ordering::SeqCst)
+        write_stats.fail(),
+        write_stats.success()
     );
-    let total = _total_latency_ms.load(std::sync::atomic::Ordering::SeqCst);
-    let max = _max_latency_ms.load(std::sync::atomic::Ordering::SeqCst);
-    let min = _min_latency_ms.load(std::sync::atomic::Ordering::SeqCst);
-    let success = _success_count.load(std::sync::atomic::Ordering::SeqCst);
-    let avg = total as f64 / success as f64;
-    let tps = success as f64 / duration.as_secs_f64();
-
-    println!("@ Max latency: {} ms", max);
-    println!("@ Min latency: {} ms", min);
-    println!("@ Avg latency: {:.2} ms", avg);
+    write_stats.print_errors();
+    let tps = stats::per_sec(write_stats.rows(), report.duration);
+
+    // worker별 히스토그램을 합쳐서 percentile 계산
+    let histogram = write_stats.latency.merge();
+    stats::print_latency_report(&histogram);
+    if batch_size > 1 {
+        runner::print_throughput(name, write_stats, report.duration);
+    }
     println!("@ Throughput: {:.2} writes/sec(TPS)", tps);
 }
+
+fn label_timeline(samples: &[TimelineSample], backend: &str, phase: &str) -> Vec<TimelineSample> {
+    samples
+        .iter()
+        .map(|sample| TimelineSample {
+            backend: backend.to_string(),
+            phase: phase.to_string(),
+            ..sample.clone()
+        })
+        .collect()
+}
@@ -0,0 +1,298 @@
+use std::path::Path;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+
+/// record field의 type
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldType {
+    Int,
+    Float,
+    Bool,
+    // dataset에서는 RFC 3339 문자열 또는 epoch milliseconds
+    Timestamp,
+    Text,
+    // 중첩된 JSON (object, array)
+    Json,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Field {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: FieldType,
+    // time-series DB에서 field 대신 tag로 저장 (text만 가능)
+    #[serde(default)]
+    pub tag: bool,
+}
+
+/// record의 field 목록 (--schema 파일)
+///
+/// ```json
+/// {"fields": [{"name": "user_id", "type": "int"}, {"name": "event", "type": "text", "tag": true}]}
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Schema {
+    pub fields: Vec<Field>,
+}
+
+/// field 하나의 값 (dataset에 없거나 null이면 Null)
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    // epoch milliseconds (UTC)
+    Timestamp(i64),
+    Text(String),
+    Json(serde_json::Value),
+}
+
+/// key와 schema 순서대로의 field 값
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub key: String,
+    pub values: Vec<Value>,
+}
+
+impl Schema {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let schema: Schema = serde_json::from_str(&text)
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+
+        schema.validate()?;
+        Ok(schema)
+    }
+
+    // field 이름은 그대로 column 이름으로 쓰므로 identifier만 허용
+    fn validate(&self) -> Result<(), String> {
+        if self.fields.is_empty() {
+            return Err("Schema has no fields".into());
+        }
+
+        for (index, field) in self.fields.iter().enumerate() {
+            let valid =
+                field.name.chars().enumerate().all(|(i, c)| {
+                    c == '_' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit())
+                });
+            if field.name.is_empty() || !valid {
+                return Err(format!("Invalid field name: {:?}", field.name));
+            }
+            if field.name == "key" {
+                return Err("Field name \"key\" is reserved for the record key".into());
+            }
+            if self.fields[..index]
+                .iter()
+                .any(|other| other.name == field.name)
+            {
+                return Err(format!("Duplicate field: {}", field.name));
+            }
+            if field.tag && field.kind != FieldType::Text {
+                return Err(format!("Tag field {} must be text", field.name));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// dataset의 value(JSON object)를 schema 순서의 값으로 변환
+    pub fn parse(&self, key: String, value: &str) -> Result<Record, String> {
+        let object: serde_json::Map<String, serde_json::Value> = serde_json::from_str(value)
+            .map_err(|e| format!("value is not a JSON object: {}", e))?;
+
+        let values = self
+            .fields
+            .iter()
+            .map(|field| match object.get(&field.name) {
+                None | Some(serde_json::Value::Null) => Ok(Value::Null),
+                Some(value) => Value::parse(field.kind, value).ok_or_else(|| {
+                    format!(
+                        "field {} is not a valid {:?}: {}",
+                        field.name, field.kind, value
+                    )
+                }),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Record { key, values })
+    }
+
+    /// time-series DB에서 point의 시각으로 쓰는 첫 번째 timestamp field
+    pub fn time_field(&self) -> Option<usize> {
+        self.fields
+            .iter()
+            .position(|field| field.kind == FieldType::Timestamp)
+    }
+}
+
+impl Value {
+    fn parse(kind: FieldType, value: &serde_json::Value) -> Option<Self> {
+        match kind {
+            FieldType::Int => value.as_i64().map(Value::Int),
+            FieldType::Float => value.as_f64().map(Value::Float),
+            FieldType::Bool => value.as_bool().map(Value::Bool),
+            FieldType::Timestamp => match value {
+                serde_json::Value::String(text) => DateTime::parse_from_rfc3339(text)
+                    .ok()
+                    .map(|time| Value::Timestamp(time.timestamp_millis())),
+                value => value.as_i64().map(Value::Timestamp),
+            },
+            FieldType::Text => value.as_str().map(|text| Value::Text(text.to_string())),
+            FieldType::Json => Some(Value::Json(value.clone())),
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Value::Null => serde_json::Value::Null,
+            Value::Int(value) => (*value).into(),
+            Value::Float(value) => (*value).into(),
+            Value::Bool(value) => (*value).into(),
+            Value::Timestamp(millis) => rfc3339(*millis).into(),
+            Value::Text(value) => value.as_str().into(),
+            Value::Json(value) => value.clone(),
+        }
+    }
+
+    /// SQL literal로 cast할 수 있는 문자열 (Null이면 None)
+    pub fn to_text(&self) -> Option<String> {
+        match self {
+            Value::Null => None,
+            Value::Int(value) => Some(value.to_string()),
+            Value::Float(value) => Some(value.to_string()),
+            Value::Bool(value) => Some(value.to_string()),
+            Value::Timestamp(millis) => Some(rfc3339(*millis)),
+            Value::Text(value) => Some(value.clone()),
+            Value::Json(value) => Some(value.to_string()),
+        }
+    }
+}
+
+impl Record {
+    /// field 이름을 key로 하는 JSON object (native 형식이 없는 backend는 이 문자열을 value로 저장)
+    pub fn to_json(&self, schema: &Schema) -> serde_json::Map<String, serde_json::Value> {
+        schema
+            .fields
+            .iter()
+            .zip(&self.values)
+            .filter(|(_, value)| **value != Value::Null)
+            .map(|(field, value)| (field.name.clone(), value.to_json()))
+            .collect()
+    }
+}
+
+// 범위를 벗어난 timestamp는 epoch으로 표시
+pub fn rfc3339(millis: i64) -> String {
+    DateTime::<Utc>::from_timestamp_millis(millis)
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema(json: &str) -> Schema {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn event_schema() -> Schema {
+        schema(
+            r#"{"fields": [
+                {"name": "user_id", "type": "int"},
+                {"name": "score", "type": "float"},
+                {"name": "active", "type": "bool"},
+                {"name": "created_at", "type": "timestamp"},
+                {"name": "event", "type": "text", "tag": true},
+                {"name": "payload", "type": "json"}
+            ]}"#,
+        )
+    }
+
+    #[test]
+    fn parses_values_in_schema_order() {
+        let record = event_schema()
+            .parse(
+                "k1".into(),
+                r#"{"payload": {"a": [1]}, "event": "click", "created_at": "2024-01-01T00:00:00Z",
+                    "active": true, "score": 1.5, "user_id": 42}"#,
+            )
+            .unwrap();
+
+        assert_eq!(record.key, "k1");
+        assert_eq!(
+            record.values,
+            vec![
+                Value::Int(42),
+                Value::Float(1.5),
+                Value::Bool(true),
+                Value::Timestamp(1_704_067_200_000),
+                Value::Text("click".into()),
+                Value::Json(serde_json::json!({"a": [1]})),
+            ]
+        );
+    }
+
+    #[test]
+    fn timestamp_accepts_epoch_millis() {
+        let schema = schema(r#"{"fields": [{"name": "at", "type": "timestamp"}]}"#);
+        let record = schema.parse("k".into(), r#"{"at": 1000}"#).unwrap();
+
+        assert_eq!(record.values, vec![Value::Timestamp(1000)]);
+    }
+
+    #[test]
+    fn missing_and_null_fields_are_null() {
+        let record = event_schema()
+            .parse("k".into(), r#"{"user_id": null, "event": "view"}"#)
+            .unwrap();
+
+        assert_eq!(record.values[0], Value::Null);
+        assert_eq!(record.values[1], Value::Null);
+        assert_eq!(record.values[4], Value::Text("view".into()));
+    }
+
+    #[test]
+    fn rejects_values_of_the_wrong_type() {
+        let schema = event_schema();
+
+        assert!(schema.parse("k".into(), r#"{"user_id": "42"}"#).is_err());
+        assert!(schema.parse("k".into(), r#"{"user_id": 1.5}"#).is_err());
+        assert!(
+            schema
+                .parse("k".into(), r#"{"created_at": "yesterday"}"#)
+                .is_err()
+        );
+        assert!(schema.parse("k".into(), r#"[1, 2]"#).is_err());
+        assert!(schema.parse("k".into(), "not json").is_err());
+    }
+
+    #[test]
+    fn validate_accepts_identifiers() {
+        assert!(event_schema().validate().is_ok());
+        assert!(
+            schema(r#"{"fields": [{"name": "_a1", "type": "int"}]}"#)
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn validate_rejects_invalid_schemas() {
+        for json in [
+            r#"{"fields": []}"#,
+            r#"{"fields": [{"name": "", "type": "int"}]}"#,
+            r#"{"fields": [{"name": "1a", "type": "int"}]}"#,
+            r#"{"fields": [{"name": "a-b", "type": "int"}]}"#,
+            r#"{"fields": [{"name": "key", "type": "text"}]}"#,
+            r#"{"fields": [{"name": "a", "type": "int"}, {"name": "a", "type": "text"}]}"#,
+            r#"{"fields": [{"name": "a", "type": "int", "tag": true}]}"#,
+        ] {
+            assert!(schema(json).validate().is_err(), "{} was accepted", json);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use database_write_performance::comparison;
+use database_write_performance::result::RunResult;
+
+#[derive(Parser)]
+#[command(name = "Benchmark Report")]
+#[command(about = "Render result files as markdown tables", long_about = None)]
+struct Cli {
+    #[arg(default_value = "results")]
+    /// Directory of result files written by the main binary
+    dir: PathBuf,
+
+    #[arg(long, default_value = "load")]
+    /// Phase to report
+    phase: String,
+
+    #[arg(long)]
+    /// Backend the others are compared against (defaults to the fastest one)
+    baseline: Option<String>,
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let results = RunResult::load_dir(&cli.dir).expect("Failed to read result directory");
+
+    match comparison::render(&results, &cli.phase, cli.baseline.as_deref()) {
+        Some(output) => print!("{}", output),
+        None => eprintln!(
+            "No results for phase '{}' in {}",
+            cli.phase,
+            cli.dir.display()
+        ),
+    }
+}
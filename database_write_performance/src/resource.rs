@@ -0,0 +1,364 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tokio::time::MissedTickBehavior;
+
+use crate::config::BackendConfig;
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+// cgroup v2 mount 위치 (hybrid 구성이면 unified 아래에 있음)
+const CGROUP_ROOTS: &[&str] = &["/sys/fs/cgroup", "/sys/fs/cgroup/unified"];
+
+/// 측정 대상 (backend 설정의 container, pid, data_dir)
+#[derive(Clone, Debug, Default)]
+pub struct Target {
+    pub cgroup: Option<PathBuf>,
+    pub data_dir: Option<PathBuf>,
+}
+
+impl Target {
+    /// container 이름은 docker inspect로 PID를 찾고, PID의 cgroup을 사용
+    pub fn resolve(config: &BackendConfig) -> Result<Self, String> {
+        let pid = match (&config.container, config.pid) {
+            (Some(container), _) => Some(container_pid(container)?),
+            (None, pid) => pid,
+        };
+
+        let cgroup = match pid {
+            Some(pid) => Some(cgroup_path(pid)?),
+            None => None,
+        };
+
+        Ok(Target {
+            cgroup,
+            data_dir: config.data_dir.as_ref().map(PathBuf::from),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cgroup.is_none() && self.data_dir.is_none()
+    }
+}
+
+fn container_pid(container: &str) -> Result<u32, String> {
+    let output = std::process::Command::new("docker")
+        .args(["inspect", "--format", "{{.State.Pid}}", container])
+        .output()
+        .map_err(|e| format!("Failed to run docker inspect: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "docker inspect {} failed: {}",
+            container,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    match String::from_utf8_lossy(&output.stdout).trim().parse() {
+        Ok(pid) if pid > 0 => Ok(pid),
+        _ => Err(format!("Container {} is not running", container)),
+    }
+}
+
+// /proc/<pid>/cgroup의 "0::<path>" 항목 (cgroup v2)
+fn cgroup_path(pid: u32) -> Result<PathBuf, String> {
+    let text = std::fs::read_to_string(format!("/proc/{}/cgroup", pid))
+        .map_err(|e| format!("Failed to read cgroup of PID {}: {}", pid, e))?;
+
+    let relative = text
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .ok_or_else(|| format!("PID {} is not in a cgroup v2 hierarchy", pid))?
+        .trim_start_matches('/');
+
+    CGROUP_ROOTS
+        .iter()
+        .map(|root| Path::new(root).join(relative))
+        .find(|path| path.join("cpu.stat").exists())
+        .ok_or_else(|| format!("cgroup v2 of PID {} not found", pid))
+}
+
+// cgroup 파일에서 읽은 누적값
+#[derive(Clone, Copy, Default)]
+struct CgroupCounters {
+    cpu_usec: Option<u64>,
+    memory_bytes: Option<u64>,
+    // memory.stat의 anon (RSS에 해당)
+    rss_bytes: Option<u64>,
+    io_read_bytes: Option<u64>,
+    io_write_bytes: Option<u64>,
+}
+
+impl CgroupCounters {
+    fn read(cgroup: &Path) -> Self {
+        let read = |name: &str| std::fs::read_to_string(cgroup.join(name)).ok();
+
+        // "key value" 형식의 줄에서 key의 값
+        let field = |text: &str, key: &str| {
+            text.lines().find_map(|line| {
+                let (name, value) = line.split_once(' ')?;
+                (name == key).then(|| value.trim().parse().ok()).flatten()
+            })
+        };
+
+        let cpu_usec = read("cpu.stat").and_then(|text| field(&text, "usage_usec"));
+        let memory_bytes = read("memory.current").and_then(|text| text.trim().parse().ok());
+        let rss_bytes = read("memory.stat").and_then(|text| field(&text, "anon"));
+
+        // io.stat: "<major>:<minor> rbytes=.. wbytes=.. ..." 장치별로 한 줄
+        let (io_read_bytes, io_write_bytes) = match read("io.stat") {
+            Some(text) => {
+                let sum = |key: &str| {
+                    text.split_whitespace()
+                        .filter_map(|pair| pair.strip_prefix(key)?.parse::<u64>().ok())
+                        .sum::<u64>()
+                };
+                (Some(sum("rbytes=")), Some(sum("wbytes=")))
+            }
+            None => (None, None),
+        };
+
+        CgroupCounters {
+            cpu_usec,
+            memory_bytes,
+            rss_bytes,
+            io_read_bytes,
+            io_write_bytes,
+        }
+    }
+}
+
+/// backend 실행 동안의 resource 사용량 (측정할 수 없는 값은 None)
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ResourceUsage {
+    pub cgroup: Option<String>,
+    pub duration_secs: f64,
+    pub cpu_secs: Option<f64>,
+    pub avg_cpu_cores: Option<f64>,
+    pub peak_cpu_cores: Option<f64>,
+    pub peak_memory_bytes: Option<u64>,
+    pub peak_rss_bytes: Option<u64>,
+    pub io_read_bytes: Option<u64>,
+    pub io_write_bytes: Option<u64>,
+    pub io_write_bytes_per_record: Option<f64>,
+    pub data_dir: Option<String>,
+    pub data_dir_bytes_before: Option<u64>,
+    pub data_dir_bytes_after: Option<u64>,
+    // data directory 증가량 / record 수
+    pub disk_bytes_per_record: Option<f64>,
+}
+
+impl ResourceUsage {
+    pub fn print(&self) {
+        if let Some(cpu_secs) = self.cpu_secs {
+            println!(
+                "@ CPU: {:.2} s (avg {:.2} cores, peak {:.2} cores)",
+                cpu_secs,
+                self.avg_cpu_cores.unwrap_or_default(),
+                self.peak_cpu_cores.unwrap_or_default()
+            );
+        }
+        if let Some(memory) = self.peak_memory_bytes {
+            println!(
+                "@ Peak memory: {} (RSS {})",
+                format_bytes(memory),
+                self.peak_rss_bytes
+                    .map(format_bytes)
+                    .unwrap_or_else(|| "-".into())
+            );
+        }
+        if let (Some(read), Some(write)) = (self.io_read_bytes, self.io_write_bytes) {
+            println!(
+                "@ IO: read {}, write {} ({:.1} bytes/record)",
+                format_bytes(read),
+                format_bytes(write),
+                self.io_write_bytes_per_record.unwrap_or_default()
+            );
+        }
+        if let (Some(before), Some(after)) = (self.data_dir_bytes_before, self.data_dir_bytes_after)
+        {
+            println!(
+                "@ Data directory: {} -> {} ({:.1} bytes/record)",
+                format_bytes(before),
+                format_bytes(after),
+                self.disk_bytes_per_record.unwrap_or_default()
+            );
+        }
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// 디스크에서 실제로 차지하는 크기 (du와 같은 방식, 읽을 수 없는 파일은 건너뜀)
+///
+/// 큰 data directory는 한참 걸리므로 runtime worker를 막지 않도록 blocking thread에서 순회
+pub async fn directory_size(path: &Path) -> Option<u64> {
+    let path = path.to_path_buf();
+
+    tokio::task::spawn_blocking(move || walk_directory(&path))
+        .await
+        .ok()
+        .flatten()
+}
+
+fn walk_directory(path: &Path) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+
+    let metadata = std::fs::symlink_metadata(path).ok()?;
+    if !metadata.is_dir() {
+        return Some(metadata.blocks() * 512);
+    }
+
+    let mut total = metadata.blocks() * 512;
+    for entry in std::fs::read_dir(path).ok()?.flatten() {
+        total += walk_directory(&entry.path()).unwrap_or(0);
+    }
+
+    Some(total)
+}
+
+pub struct Sampler {
+    target: Target,
+    data_dir_bytes_before: Option<u64>,
+    stop: tokio::sync::oneshot::Sender<()>,
+    handle: tokio::task::JoinHandle<Samples>,
+}
+
+// sampling 중에 모은 값
+#[derive(Default)]
+struct Samples {
+    first: CgroupCounters,
+    last: CgroupCounters,
+    peak_cpu_cores: Option<f64>,
+    peak_memory_bytes: Option<u64>,
+    peak_rss_bytes: Option<u64>,
+    duration: Duration,
+}
+
+impl Sampler {
+    /// data directory 크기를 먼저 재고, stop할 때까지 1초마다 cgroup을 sampling
+    pub async fn start(target: Target) -> Self {
+        let data_dir_bytes_before = match &target.data_dir {
+            Some(data_dir) => directory_size(data_dir).await,
+            None => None,
+        };
+        let (stop, mut stop_receiver) = tokio::sync::oneshot::channel();
+        let cgroup = target.cgroup.clone();
+
+        let handle = tokio::spawn(async move {
+            let mut samples = Samples::default();
+            let Some(cgroup) = cgroup else {
+                let _ = stop_receiver.await;
+                return samples;
+            };
+
+            let start = Instant::now();
+            let mut interval =
+                tokio::time::interval_at((start + SAMPLE_INTERVAL).into(), SAMPLE_INTERVAL);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            samples.first = CgroupCounters::read(&cgroup);
+            let mut previous = samples.first;
+            let mut previous_at = start;
+
+            loop {
+                let stopped = tokio::select! {
+                    _ = interval.tick() => false,
+                    _ = &mut stop_receiver => true,
+                };
+
+                let now = Instant::now();
+                let counters = CgroupCounters::read(&cgroup);
+
+                if let (Some(before), Some(after)) = (previous.cpu_usec, counters.cpu_usec) {
+                    let seconds = (now - previous_at).as_secs_f64();
+                    let cores = after.saturating_sub(before) as f64 / 1_000_000.0 / seconds;
+                    samples.peak_cpu_cores = Some(samples.peak_cpu_cores.unwrap_or(0.0).max(cores));
+                }
+                samples.peak_memory_bytes = samples.peak_memory_bytes.max(counters.memory_bytes);
+                samples.peak_rss_bytes = samples.peak_rss_bytes.max(counters.rss_bytes);
+
+                samples.last = counters;
+                previous = counters;
+                previous_at = now;
+
+                if stopped {
+                    break;
+                }
+            }
+
+            samples.duration = start.elapsed();
+            samples
+        });
+
+        Sampler {
+            target,
+            data_dir_bytes_before,
+            stop,
+            handle,
+        }
+    }
+
+    pub async fn finish(self, record_count: usize) -> ResourceUsage {
+        let _ = self.stop.send(());
+        let samples = self.handle.await.expect("Resource sampler panicked");
+
+        let data_dir_bytes_after = match &self.target.data_dir {
+            Some(data_dir) => directory_size(data_dir).await,
+            None => None,
+        };
+
+        let delta = |first: Option<u64>, last: Option<u64>| Some(last?.saturating_sub(first?));
+        let per_record = |bytes: Option<u64>| {
+            bytes
+                .filter(|_| record_count > 0)
+                .map(|bytes| bytes as f64 / record_count as f64)
+        };
+
+        let cpu_secs = delta(samples.first.cpu_usec, samples.last.cpu_usec)
+            .map(|usec| usec as f64 / 1_000_000.0);
+        let io_write_bytes = delta(samples.first.io_write_bytes, samples.last.io_write_bytes);
+        let seconds = samples.duration.as_secs_f64();
+
+        ResourceUsage {
+            cgroup: self
+                .target
+                .cgroup
+                .as_ref()
+                .map(|path| path.display().to_string()),
+            duration_secs: seconds,
+            cpu_secs,
+            avg_cpu_cores: cpu_secs.filter(|_| seconds > 0.0).map(|cpu| cpu / seconds),
+            peak_cpu_cores: samples.peak_cpu_cores,
+            peak_memory_bytes: samples.peak_memory_bytes,
+            peak_rss_bytes: samples.peak_rss_bytes,
+            io_read_bytes: delta(samples.first.io_read_bytes, samples.last.io_read_bytes),
+            io_write_bytes,
+            io_write_bytes_per_record: per_record(io_write_bytes),
+            data_dir: self
+                .target
+                .data_dir
+                .as_ref()
+                .map(|path| path.display().to_string()),
+            data_dir_bytes_before: self.data_dir_bytes_before,
+            data_dir_bytes_after,
+            disk_bytes_per_record: per_record(delta(
+                self.data_dir_bytes_before,
+                data_dir_bytes_after,
+            )),
+        }
+    }
+}
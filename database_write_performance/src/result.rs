@@ -0,0 +1,156 @@
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::db::Durability;
+use crate::indexes::IndexReport;
+use crate::resource::ResourceUsage;
+use crate::runner::PhaseSummary;
+use crate::sweep::SweepReport;
+use crate::verify::VerifyReport;
+
+/// 실행 조건 (결과를 비교할 때 같은 조건인지 확인하는 용도)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RunMetadata {
+    pub backend: String,
+    pub record_count: usize,
+    pub concurrency: usize,
+    pub batch_size: usize,
+    // open-loop 모드의 목표 요청 수
+    pub target_rate: Option<f64>,
+    // --durability (None이면 각 backend의 기본 설정)
+    #[serde(default)]
+    pub durability: Option<Durability>,
+    // load phase와 이후 phase에서 table에 있던 secondary index 수 (--indexes의 마지막 값)
+    #[serde(default)]
+    pub indexes: usize,
+    pub dataset: String,
+    pub dataset_sha256: String,
+    // git 저장소 밖에서 실행하면 None
+    pub git_revision: Option<String>,
+    // RFC 3339 (UTC)
+    pub timestamp: String,
+}
+
+/// backend 하나를 실행한 결과 (결과 파일 하나에 해당)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RunResult {
+    pub metadata: RunMetadata,
+    pub phases: Vec<PhaseSummary>,
+    // --verify로 실행한 경우 load phase 직후의 검증 결과
+    #[serde(default)]
+    pub verify: Option<VerifyReport>,
+    // backend 설정에 container/pid/data_dir가 있는 경우 측정한 resource 사용량
+    #[serde(default)]
+    pub resources: Option<ResourceUsage>,
+    // --sweep으로 실행한 경우 concurrency별 load phase 결과 (metadata.concurrency는 best)
+    #[serde(default)]
+    pub sweep: Option<SweepReport>,
+    // --indexes에 index 수를 여러 개 지정한 경우 index 수별 load phase 결과
+    #[serde(default)]
+    pub indexes: Option<IndexReport>,
+    // SIGINT/SIGTERM으로 중간에 멈춰서 일부 phase만 실행됨
+    #[serde(default)]
+    pub partial: bool,
+}
+
+impl RunResult {
+    pub fn phase(&self, name: &str) -> Option<&PhaseSummary> {
+        self.phases.iter().find(|phase| phase.name == name)
+    }
+
+    /// dir/<backend>-<timestamp>.json 으로 저장하고 경로를 반환
+    ///
+    /// 같은 이름의 파일이 이미 있으면 덮어쓰지 않고 -1, -2, ... 를 붙임
+    pub fn save(&self, dir: &Path) -> std::io::Result<PathBuf> {
+        std::fs::create_dir_all(dir)?;
+
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        let name = format!(
+            "{}-{}",
+            self.metadata.backend,
+            self.metadata.timestamp.replace([':', '-'], "")
+        );
+
+        let mut suffix = 0;
+        loop {
+            let path = match suffix {
+                0 => dir.join(format!("{}.json", name)),
+                _ => dir.join(format!("{}-{}.json", name, suffix)),
+            };
+
+            let mut file = match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => file,
+                Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => {
+                    suffix += 1;
+                    continue;
+                }
+                Err(error) => return Err(error),
+            };
+            file.write_all(json.as_bytes())?;
+
+            return Ok(path);
+        }
+    }
+
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        let file = BufReader::new(File::open(path)?);
+        serde_json::from_reader(file).map_err(std::io::Error::other)
+    }
+
+    /// dir 안의 *.json 결과 파일을 모두 읽음 (읽을 수 없는 파일은 경고 후 건너뜀)
+    pub fn load_dir(dir: &Path) -> std::io::Result<Vec<Self>> {
+        let mut results = vec![];
+
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|extension| extension != "json") {
+                continue;
+            }
+
+            match RunResult::load(&path) {
+                Ok(result) => results.push(result),
+                Err(error) => eprintln!("Skipping {}: {}", path.display(), error),
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+pub fn dataset_sha256(path: &Path) -> std::io::Result<String> {
+    let mut file = BufReader::new(File::open(path)?);
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+pub fn git_revision() -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+pub fn timestamp() -> String {
+    // 같은 초에 끝난 실행의 결과 파일 이름이 겹치지 않도록 millisecond까지
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+}
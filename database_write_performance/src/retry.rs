@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use rand::Rng;
+
+use crate::db::ErrorKind;
+
+/// 실패한 요청을 다시 보내는 방식
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum RetryStrategy {
+    // 재시도하지 않음
+    None,
+    // 같은 간격으로 최대 횟수까지
+    Fixed,
+    // 간격을 2배씩 늘리고 (최대 max_delay) 0 ~ 간격 사이에서 무작위로 기다림 (full jitter)
+    Exponential,
+    // exponential과 같은 간격으로, 첫 시도부터 deadline이 지날 때까지 (횟수 제한 없음)
+    Deadline,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    pub strategy: RetryStrategy,
+    // 첫 시도를 포함한 최대 시도 횟수 (deadline에서는 사용하지 않음)
+    pub max_attempts: usize,
+    // fixed의 간격, exponential/deadline의 첫 간격
+    pub delay: Duration,
+    pub max_delay: Duration,
+    pub deadline: Duration,
+}
+
+impl RetryPolicy {
+    /// attempt번째 시도가 kind로 실패했을 때 다음 시도까지 기다릴 시간 (None이면 포기)
+    ///
+    /// elapsed는 첫 시도를 시작한 뒤 지난 시간
+    pub fn backoff(&self, attempt: usize, elapsed: Duration, kind: ErrorKind) -> Option<Duration> {
+        // 지원하지 않는 연산은 다시 보내도 실패함
+        if kind == ErrorKind::Unsupported {
+            return None;
+        }
+
+        match self.strategy {
+            RetryStrategy::None => None,
+            RetryStrategy::Fixed => (attempt < self.max_attempts).then_some(self.delay),
+            RetryStrategy::Exponential => {
+                (attempt < self.max_attempts).then(|| self.jittered(attempt))
+            }
+            RetryStrategy::Deadline => {
+                let remaining = self.deadline.checked_sub(elapsed)?;
+                (!remaining.is_zero()).then(|| self.jittered(attempt).min(remaining))
+            }
+        }
+    }
+
+    // delay * 2^(attempt - 1)을 max_delay로 자르고 0 ~ 그 값 사이에서 무작위
+    fn jittered(&self, attempt: usize) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31) as u32;
+        let ceiling = self
+            .delay
+            .saturating_mul(2u32.pow(exponent))
+            .min(self.max_delay);
+
+        ceiling.mul_f64(rand::rng().random::<f64>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(strategy: RetryStrategy) -> RetryPolicy {
+        RetryPolicy {
+            strategy,
+            max_attempts: 3,
+            delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+            deadline: Duration::from_secs(1),
+        }
+    }
+
+    #[test]
+    fn none_never_retries() {
+        let policy = policy(RetryStrategy::None);
+
+        assert_eq!(policy.backoff(1, Duration::ZERO, ErrorKind::Timeout), None);
+    }
+
+    #[test]
+    fn fixed_retries_until_max_attempts() {
+        let policy = policy(RetryStrategy::Fixed);
+
+        for attempt in 1..3 {
+            assert_eq!(
+                policy.backoff(attempt, Duration::ZERO, ErrorKind::Timeout),
+                Some(Duration::from_millis(100))
+            );
+        }
+        assert_eq!(policy.backoff(3, Duration::ZERO, ErrorKind::Timeout), None);
+    }
+
+    #[test]
+    fn exponential_is_capped_by_max_delay() {
+        let policy = policy(RetryStrategy::Exponential);
+
+        for _ in 0..100 {
+            let first = policy
+                .backoff(1, Duration::ZERO, ErrorKind::Throttled)
+                .unwrap();
+            assert!(first <= Duration::from_millis(100));
+
+            let second = policy
+                .backoff(2, Duration::ZERO, ErrorKind::Throttled)
+                .unwrap();
+            assert!(second <= Duration::from_millis(200));
+        }
+
+        // 100 * 2^9는 max_delay로 잘림
+        let policy = RetryPolicy {
+            max_attempts: 20,
+            ..policy
+        };
+        for _ in 0..100 {
+            let delay = policy
+                .backoff(10, Duration::ZERO, ErrorKind::Throttled)
+                .unwrap();
+            assert!(delay <= Duration::from_millis(300));
+        }
+        assert_eq!(
+            policy.backoff(20, Duration::ZERO, ErrorKind::Throttled),
+            None
+        );
+    }
+
+    #[test]
+    fn deadline_ignores_max_attempts_and_stops_at_deadline() {
+        let policy = policy(RetryStrategy::Deadline);
+
+        assert!(
+            policy
+                .backoff(100, Duration::from_millis(500), ErrorKind::Timeout)
+                .is_some()
+        );
+
+        // 남은 시간보다 오래 기다리지 않음
+        for _ in 0..100 {
+            let delay = policy
+                .backoff(5, Duration::from_millis(950), ErrorKind::Timeout)
+                .unwrap();
+            assert!(delay <= Duration::from_millis(50));
+        }
+
+        assert_eq!(
+            policy.backoff(1, Duration::from_secs(1), ErrorKind::Timeout),
+            None
+        );
+        assert_eq!(
+            policy.backoff(1, Duration::from_secs(2), ErrorKind::Timeout),
+            None
+        );
+    }
+
+    #[test]
+    fn unsupported_is_never_retried() {
+        for strategy in [
+            RetryStrategy::Fixed,
+            RetryStrategy::Exponential,
+            RetryStrategy::Deadline,
+        ] {
+            assert_eq!(
+                policy(strategy).backoff(1, Duration::ZERO, ErrorKind::Unsupported),
+                None
+            );
+        }
+    }
+}
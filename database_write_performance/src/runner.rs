@@ -0,0 +1,696 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+
+use crate::db::Database;
+use crate::interrupt::Interrupt;
+use crate::record::{Record, Schema};
+use crate::retry::RetryPolicy;
+use crate::stats::{self, LatencyRecorder, OperationStats, OperationSummary};
+use crate::timeline::{self, TimelineSample};
+
+/// open-loop 모드에서 요청 간격을 정하는 방식
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum Arrival {
+    // 1 / rate 간격으로 일정하게
+    Fixed,
+    // 평균 1 / rate인 지수분포 간격 (Poisson process)
+    Poisson,
+}
+
+/// open-loop 모드: 응답과 관계없이 정해진 시각에 요청을 보냄
+#[derive(Clone, Copy, Debug)]
+pub struct RateLimit {
+    // 초당 요청 수
+    pub requests_per_sec: f64,
+    pub arrival: Arrival,
+}
+
+impl RateLimit {
+    // 다음 요청까지의 간격
+    fn next_interval(&self, rng: &mut StdRng) -> Duration {
+        let mean = 1.0 / self.requests_per_sec;
+
+        match self.arrival {
+            Arrival::Fixed => Duration::from_secs_f64(mean),
+            Arrival::Poisson => {
+                // 1 - U는 (0, 1] 범위이므로 ln(0)이 나오지 않음
+                let uniform: f64 = rng.random();
+                Duration::from_secs_f64(-(1.0 - uniform).ln() * mean)
+            }
+        }
+    }
+}
+
+/// concurrency를 worker_count의 step_percent%부터 interval마다 step_percent%씩 올림
+#[derive(Clone, Copy, Debug)]
+pub struct Ramp {
+    pub step_percent: usize,
+    pub interval: Duration,
+}
+
+impl Ramp {
+    // 100%에 도달하기 전 단계들의 concurrency
+    fn levels(&self, worker_count: usize) -> Vec<usize> {
+        (1..)
+            .map(|step| step * self.step_percent)
+            .take_while(|percent| *percent < 100)
+            .map(|percent| (worker_count * percent / 100).max(1))
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RunOptions {
+    // 동시에 실행할 최대 요청 수
+    pub worker_count: usize,
+    pub retry: RetryPolicy,
+    // None이면 closed-loop (이전 요청이 끝나야 다음 요청을 보냄)
+    pub rate: Option<RateLimit>,
+    // phase 시작 후 이 시간 동안 보낸 요청의 통계는 버림
+    pub warmup: Duration,
+    // warm-up 이후 concurrency를 단계적으로 올림 (단계별 통계는 따로 보고)
+    pub ramp: Option<Ramp>,
+    // phase 시작 후 이 시간이 지나면 더 이상 요청을 보내지 않음
+    pub duration: Option<Duration>,
+    // interrupt를 받은 뒤 진행 중인 요청을 기다리는 시간
+    pub grace: Duration,
+}
+
+#[derive(Clone, Debug)]
+pub enum Operation {
+    Read {
+        key: String,
+    },
+    Write {
+        key: String,
+        value: String,
+    },
+    WriteBatch {
+        entries: Vec<(String, String)>,
+    },
+    // --schema로 읽은 typed record (통계는 Write, WriteBatch와 같이 집계)
+    WriteRecord {
+        schema: Arc<Schema>,
+        record: Record,
+    },
+    WriteRecords {
+        schema: Arc<Schema>,
+        records: Vec<Record>,
+    },
+    Scan {
+        start_key: String,
+        count: usize,
+    },
+    ReadModifyWrite {
+        key: String,
+        value: String,
+    },
+}
+
+impl Operation {
+    // 요청 하나가 처리하는 row 수
+    pub fn row_count(&self) -> u64 {
+        match self {
+            Operation::WriteBatch { entries } => entries.len() as u64,
+            Operation::WriteRecords { records, .. } => records.len() as u64,
+            _ => 1,
+        }
+    }
+}
+
+/// 하나의 phase(load, read-only, mixed, YCSB)에서 수집한 통계
+pub struct PhaseStats {
+    pub reads: OperationStats,
+    pub writes: OperationStats,
+    pub write_batches: OperationStats,
+    pub scans: OperationStats,
+    pub read_modify_writes: OperationStats,
+    // 연산 종류와 관계없이 현재 timeline 구간의 latency (sampler가 1초마다 비움)
+    pub interval_latency: LatencyRecorder,
+}
+
+impl PhaseStats {
+    pub fn new(worker_count: usize) -> Self {
+        PhaseStats {
+            reads: OperationStats::new(worker_count),
+            writes: OperationStats::new(worker_count),
+            write_batches: OperationStats::new(worker_count),
+            scans: OperationStats::new(worker_count),
+            read_modify_writes: OperationStats::new(worker_count),
+            interval_latency: LatencyRecorder::new(worker_count),
+        }
+    }
+
+    pub fn by_operation(&self, operation: &Operation) -> &OperationStats {
+        match operation {
+            Operation::Read { .. } => &self.reads,
+            Operation::Write { .. } | Operation::WriteRecord { .. } => &self.writes,
+            Operation::WriteBatch { .. } | Operation::WriteRecords { .. } => &self.write_batches,
+            Operation::Scan { .. } => &self.scans,
+            Operation::ReadModifyWrite { .. } => &self.read_modify_writes,
+        }
+    }
+
+    pub fn all(&self) -> [(&'static str, &OperationStats); 5] {
+        [
+            ("Read", &self.reads),
+            ("Write", &self.writes),
+            ("WriteBatch", &self.write_batches),
+            ("Scan", &self.scans),
+            ("ReadModifyWrite", &self.read_modify_writes),
+        ]
+    }
+}
+
+/// warm-up 또는 ramp 한 단계의 통계
+pub struct StageReport {
+    pub concurrency: usize,
+    pub duration: Duration,
+    pub stats: Arc<PhaseStats>,
+}
+
+pub struct PhaseReport {
+    pub request_count: u64,
+    // steady state에 보낸 요청 수 (request_count는 warm-up, ramp 포함)
+    pub steady_request_count: u64,
+    // steady state (warm-up, ramp 이후) 구간의 길이
+    pub duration: Duration,
+    pub rate: Option<RateLimit>,
+    // steady state에 보낸 요청의 통계
+    pub stats: Arc<PhaseStats>,
+    // 1초 단위 timeline (warm-up, ramp 포함)
+    pub timeline: Vec<TimelineSample>,
+    pub warmup: Option<StageReport>,
+    pub steps: Vec<StageReport>,
+    // SIGINT/SIGTERM으로 중간에 멈춤
+    pub interrupted: bool,
+    // grace period 안에 끝나지 않아 결과를 기다리지 않은 요청 수
+    pub abandoned: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhaseSummary {
+    pub name: String,
+    pub request_count: u64,
+    pub duration_secs: f64,
+    // open-loop 모드의 목표 요청 수 (closed-loop이면 None)
+    pub target_rate: Option<f64>,
+    pub operations: BTreeMap<String, OperationSummary>,
+    // 통계를 버린 warm-up 구간 (초)
+    #[serde(default)]
+    pub warmup_secs: f64,
+    // ramp 단계별 통계 (steady state 이전)
+    #[serde(default)]
+    pub steps: Vec<StepSummary>,
+    #[serde(default)]
+    pub interrupted: bool,
+    #[serde(default)]
+    pub abandoned_count: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StepSummary {
+    pub concurrency: usize,
+    pub duration_secs: f64,
+    pub operations: BTreeMap<String, OperationSummary>,
+}
+
+// 요청이 있었던 연산만
+fn operation_summaries(
+    phase_stats: &PhaseStats,
+    duration: Duration,
+) -> BTreeMap<String, OperationSummary> {
+    phase_stats
+        .all()
+        .into_iter()
+        .filter(|(_, operation_stats)| operation_stats.success() > 0 || operation_stats.fail() > 0)
+        .map(|(operation, operation_stats)| {
+            (operation.to_string(), operation_stats.summary(duration))
+        })
+        .collect()
+}
+
+impl PhaseReport {
+    pub fn summary(&self, name: &str) -> PhaseSummary {
+        PhaseSummary {
+            name: name.to_string(),
+            request_count: self.request_count,
+            duration_secs: self.duration.as_secs_f64(),
+            target_rate: self.rate.map(|rate| rate.requests_per_sec),
+            operations: operation_summaries(&self.stats, self.duration),
+            warmup_secs: self
+                .warmup
+                .as_ref()
+                .map_or(0.0, |warmup| warmup.duration.as_secs_f64()),
+            steps: self
+                .steps
+                .iter()
+                .map(|step| StepSummary {
+                    concurrency: step.concurrency,
+                    duration_secs: step.duration.as_secs_f64(),
+                    operations: operation_summaries(&step.stats, step.duration),
+                })
+                .collect(),
+            interrupted: self.interrupted,
+            abandoned_count: self.abandoned,
+        }
+    }
+
+    pub fn print(&self) {
+        println!("@ All requests sent: {}", self.request_count);
+        println!("@ Completed in {:?}", self.duration);
+        self.print_interrupted();
+        self.print_rate();
+        self.print_stages();
+
+        for (name, operation_stats) in self.stats.all() {
+            let success = operation_stats.success();
+            let fail = operation_stats.fail();
+            if success == 0 && fail == 0 {
+                continue;
+            }
+
+            println!("@ {} operations", name);
+            println!("@ Fail count: {}, Success count: {}", fail, success);
+            if operation_stats.not_found() > 0 {
+                println!("@ Not found count: {}", operation_stats.not_found());
+            }
+            operation_stats.print_errors();
+
+            stats::print_latency_report(&operation_stats.latency.merge());
+            print_throughput(name, operation_stats, self.duration);
+        }
+    }
+
+    /// warm-up, ramp, steady state를 합쳐 성공한 write가 쓴 row 수 (실패한 요청은 제외)
+    pub fn written_rows(&self) -> u64 {
+        self.warmup
+            .iter()
+            .chain(&self.steps)
+            .map(|stage| &stage.stats)
+            .chain([&self.stats])
+            .map(|stats| stats.writes.rows() + stats.write_batches.rows())
+            .sum()
+    }
+}
+
+impl PhaseReport {
+    /// warm-up과 ramp 단계를 한 줄씩 출력 (steady state 통계와 분리)
+    pub fn print_stages(&self) {
+        if let Some(warmup) = &self.warmup {
+            let request_count: u64 = warmup
+                .stats
+                .all()
+                .iter()
+                .map(|(_, operation_stats)| operation_stats.success() + operation_stats.fail())
+                .sum();
+            println!(
+                "@ Warm-up: {} requests in {:.1} s at concurrency {} (discarded)",
+                request_count,
+                warmup.duration.as_secs_f64(),
+                warmup.concurrency
+            );
+        }
+
+        for (index, step) in self.steps.iter().enumerate() {
+            let mut histogram = stats::new_histogram();
+            let mut success = 0;
+            let mut rows = 0;
+            for (_, operation_stats) in step.stats.all() {
+                histogram
+                    .add(operation_stats.latency.merge())
+                    .expect("Failed to merge histogram");
+                success += operation_stats.success();
+                rows += operation_stats.rows();
+            }
+
+            println!(
+                "@ Ramp step {} (concurrency {}, {:.1} s): {:.2} requests/sec, {:.2} rows/sec, p50 {:.3} ms, p99 {:.3} ms",
+                index + 1,
+                step.concurrency,
+                step.duration.as_secs_f64(),
+                stats::per_sec(success, step.duration),
+                stats::per_sec(rows, step.duration),
+                stats::us_to_ms(histogram.value_at_percentile(50.0)),
+                stats::us_to_ms(histogram.value_at_percentile(99.0))
+            );
+        }
+
+        if self.duration.is_zero() && (self.warmup.is_some() || !self.steps.is_empty()) {
+            println!("@ Phase ended before the steady state: no steady-state results");
+        }
+    }
+
+    /// interrupt로 멈춘 경우 표시
+    pub fn print_interrupted(&self) {
+        if self.interrupted {
+            println!(
+                "@ Interrupted: partial results ({} in-flight requests abandoned after the grace period)",
+                self.abandoned
+            );
+        }
+    }
+
+    /// open-loop 모드에서 목표 요청 수와 steady state에 실제로 보낸 요청 수를 비교
+    pub fn print_rate(&self) {
+        if let Some(rate) = self.rate {
+            println!(
+                "@ Target rate: {:.2} requests/sec ({:?}), achieved: {:.2} requests/sec",
+                rate.requests_per_sec,
+                rate.arrival,
+                stats::per_sec(self.steady_request_count, self.duration)
+            );
+        }
+    }
+}
+
+/// batch write는 batch 수와 row 수를 함께 출력
+pub fn print_throughput(name: &str, operation_stats: &OperationStats, duration: Duration) {
+    if name == "WriteBatch" {
+        println!(
+            "@ Throughput: {:.2} batches/sec, {:.2} rows/sec",
+            stats::per_sec(operation_stats.success(), duration),
+            stats::per_sec(operation_stats.rows(), duration)
+        );
+    } else {
+        println!(
+            "@ Throughput: {:.2} {}s/sec",
+            stats::per_sec(operation_stats.success(), duration),
+            name.to_lowercase()
+        );
+    }
+}
+
+/// receiver에서 받은 operation을 최대 worker_count개까지 동시에 실행
+///
+/// options.rate가 있으면 open-loop로 동작: 각 요청의 예정 시각을 미리 정하고,
+/// latency를 실제 시작 시각이 아닌 예정 시각부터 측정해서 (coordinated omission 보정)
+/// backend가 느려져 요청이 밀린 시간도 latency에 포함시킨다.
+pub async fn run_phase(
+    db: Arc<dyn Database + Send + Sync>,
+    options: RunOptions,
+    interrupt: &Interrupt,
+    mut receiver: tokio::sync::mpsc::Receiver<Operation>,
+) -> PhaseReport {
+    let new_stats = || Arc::new(PhaseStats::new(options.worker_count));
+
+    // warm-up -> ramp 단계들 -> steady state 순서. 요청은 보낸 시점의 단계에 기록
+    let levels = options
+        .ramp
+        .map(|ramp| ramp.levels(options.worker_count))
+        .unwrap_or_default();
+    let ramp_interval = options.ramp.map_or(Duration::ZERO, |ramp| ramp.interval);
+    let warmup_stats = new_stats();
+    let step_stats: Vec<Arc<PhaseStats>> = levels.iter().map(|_| new_stats()).collect();
+    let phase_stats = new_stats();
+
+    let start = std::time::Instant::now();
+    let ramp_start = start + options.warmup;
+    let steady_start = ramp_start + ramp_interval * levels.len() as u32;
+
+    let stage_stats = |now: Instant| {
+        if now < ramp_start {
+            &warmup_stats
+        } else if now < steady_start {
+            let step = (now - ramp_start).as_nanos() / ramp_interval.as_nanos();
+            &step_stats[step as usize]
+        } else {
+            &phase_stats
+        }
+    };
+
+    let request_count = Arc::new(AtomicU64::new(0));
+    let done_count = Arc::new(AtomicU64::new(0));
+    let initial_concurrency = levels.first().copied().unwrap_or(options.worker_count);
+    let semaphore = Arc::new(tokio::sync::Semaphore::new(initial_concurrency));
+
+    // 단계가 바뀔 때마다 permit을 추가
+    let ramp = {
+        let semaphore = semaphore.clone();
+        let targets: Vec<usize> = levels
+            .iter()
+            .skip(1)
+            .copied()
+            .chain([options.worker_count])
+            .collect();
+
+        tokio::spawn(async move {
+            let mut current = initial_concurrency;
+            for (step, target) in targets.into_iter().enumerate() {
+                let at = ramp_start + ramp_interval * (step as u32 + 1);
+                tokio::time::sleep_until(at.into()).await;
+                semaphore.add_permits(target.saturating_sub(current));
+                current = target;
+            }
+        })
+    };
+
+    let mut stages = vec![warmup_stats.clone()];
+    stages.extend(step_stats.iter().cloned());
+    stages.push(phase_stats.clone());
+
+    let (stop_sampler, stop_receiver) = tokio::sync::oneshot::channel();
+    let sampler = timeline::spawn_sampler(
+        stages,
+        request_count.clone(),
+        done_count.clone(),
+        stop_receiver,
+    );
+
+    // duration이 지나거나 interrupt를 받으면 더 이상 요청을 보내지 않음
+    let stop = {
+        let deadline = options.duration.map(|duration| start + duration);
+        let mut interrupt = interrupt.clone();
+
+        async move {
+            match deadline {
+                Some(deadline) => tokio::select! {
+                    _ = tokio::time::sleep_until(deadline.into()) => {}
+                    _ = interrupt.wait() => {}
+                },
+                None => interrupt.wait().await,
+            }
+        }
+    };
+    tokio::pin!(stop);
+
+    let mut rng = StdRng::from_os_rng();
+    let mut next_start = start;
+    let mut tasks = tokio::task::JoinSet::new();
+    let mut steady_request_count = 0;
+
+    loop {
+        let operation = tokio::select! {
+            biased;
+            _ = &mut stop => break,
+            operation = receiver.recv() => match operation {
+                Some(operation) => operation,
+                None => break,
+            },
+        };
+
+        // open-loop: 예정 시각까지 대기 (이미 지났으면 바로 보냄)
+        let intended_start = match options.rate {
+            Some(rate) => {
+                let intended_start = next_start;
+                next_start += rate.next_interval(&mut rng);
+                tokio::select! {
+                    biased;
+                    _ = &mut stop => break,
+                    _ = tokio::time::sleep_until(intended_start.into()) => {}
+                }
+                Some(intended_start)
+            }
+            None => None,
+        };
+
+        // recv 후 바로 세마포어 획득 - 이 지점에서 블록됨
+        let permit = tokio::select! {
+            biased;
+            _ = &mut stop => break,
+            permit = semaphore.clone().acquire_owned() => permit.unwrap(),
+        };
+
+        let worker_id = request_count.fetch_add(1, Ordering::SeqCst) as usize;
+
+        let db = db.clone();
+        let done_count = done_count.clone();
+        let now = Instant::now();
+        let phase_stats = stage_stats(now).clone();
+        if now >= steady_start {
+            steady_request_count += 1;
+        }
+
+        tasks.spawn(async move {
+            // permit을 spawn 내부로 이동
+            let _permit = permit;
+
+            execute(
+                db.as_ref(),
+                &phase_stats,
+                options.retry,
+                worker_id,
+                intended_start,
+                &operation,
+            )
+            .await;
+
+            done_count.fetch_add(1, Ordering::SeqCst);
+            // _permit이 drop되면서 자동으로 세마포어 해제
+        });
+
+        // 끝난 task 정리
+        while tasks.try_join_next().is_some() {}
+    }
+
+    // producer가 더 보내지 않도록 닫음
+    drop(receiver);
+    let request_count = request_count.load(Ordering::SeqCst);
+
+    // 진행 중인 요청이 모두 끝날 때까지 대기. interrupt를 받으면 grace period까지만 기다림
+    let drained = {
+        let mut interrupt = interrupt.clone();
+        let drain = async { while tasks.join_next().await.is_some() {} };
+
+        tokio::select! {
+            _ = drain => true,
+            _ = async {
+                interrupt.wait().await;
+                tokio::time::sleep(options.grace).await;
+            } => false,
+        }
+    };
+
+    let abandoned = if drained { 0 } else { tasks.len() as u64 };
+    tasks.abort_all();
+
+    let end = Instant::now();
+    ramp.abort();
+
+    let _ = stop_sampler.send(());
+    let timeline = sampler.await.expect("Timeline sampler panicked");
+
+    // [from, to) 구간 중 phase가 실행된 시간
+    let stage_duration =
+        |from: Instant, to: Instant| to.min(end).saturating_duration_since(from.min(end));
+
+    let warmup = (!options.warmup.is_zero()).then(|| StageReport {
+        concurrency: initial_concurrency,
+        duration: stage_duration(start, ramp_start),
+        stats: warmup_stats,
+    });
+
+    let steps = levels
+        .iter()
+        .zip(step_stats)
+        .enumerate()
+        .map(|(step, (concurrency, stats))| {
+            let from = ramp_start + ramp_interval * step as u32;
+            StageReport {
+                concurrency: *concurrency,
+                duration: stage_duration(from, from + ramp_interval),
+                stats,
+            }
+        })
+        // phase가 먼저 끝나서 시작하지 못한 단계는 제외
+        .filter(|step| !step.duration.is_zero())
+        .collect();
+
+    PhaseReport {
+        request_count,
+        steady_request_count,
+        duration: end.saturating_duration_since(steady_start),
+        rate: options.rate,
+        stats: phase_stats,
+        timeline,
+        warmup,
+        steps,
+        interrupted: interrupt.is_set(),
+        abandoned,
+    }
+}
+
+async fn execute(
+    db: &(dyn Database + Send + Sync),
+    phase_stats: &PhaseStats,
+    retry: RetryPolicy,
+    worker_id: usize,
+    intended_start: Option<Instant>,
+    operation: &Operation,
+) {
+    let operation_stats = phase_stats.by_operation(operation);
+    let first_start = Instant::now();
+
+    for attempt in 1.. {
+        // open-loop에서는 재시도까지 포함해서 예정 시각부터 측정
+        let operation_start = intended_start.unwrap_or_else(Instant::now);
+
+        let result = match operation {
+            Operation::Read { key } => db.read(key).await.map(|value| value.is_some()),
+            Operation::Write { key, value } => db.write(key, value).await.map(|_| true),
+            Operation::WriteBatch { entries } => db.write_batch(entries).await.map(|_| true),
+            Operation::WriteRecord { schema, record } => {
+                db.write_record(schema, record).await.map(|_| true)
+            }
+            Operation::WriteRecords { schema, records } => {
+                db.write_records(schema, records).await.map(|_| true)
+            }
+            Operation::Scan { start_key, count } => db
+                .scan(start_key, *count)
+                .await
+                .map(|rows| !rows.is_empty()),
+            Operation::ReadModifyWrite { key, value } => match db.read(key).await {
+                Ok(current) => db.write(key, value).await.map(|_| current.is_some()),
+                Err(error) => Err(error),
+            },
+        };
+
+        match result {
+            Ok(found) => {
+                let latency = operation_start.elapsed();
+                operation_stats.latency.record(worker_id, latency);
+                phase_stats.interval_latency.record(worker_id, latency);
+                operation_stats.success_count.fetch_add(1, Ordering::SeqCst);
+                operation_stats
+                    .row_count
+                    .fetch_add(operation.row_count(), Ordering::SeqCst);
+                if !found {
+                    operation_stats
+                        .not_found_count
+                        .fetch_add(1, Ordering::SeqCst);
+                }
+                if attempt > 1 {
+                    operation_stats
+                        .retried_success_count
+                        .fetch_add(1, Ordering::SeqCst);
+                }
+
+                break;
+            }
+            Err(error) => {
+                let kind = error.kind();
+
+                match retry.backoff(attempt, first_start.elapsed(), kind) {
+                    Some(delay) => {
+                        operation_stats.retries.add(kind);
+                        tokio::time::sleep(delay).await;
+                    }
+                    None => {
+                        // 재시도를 모두 썼거나 재시도해도 소용없는 error
+                        operation_stats.failures.add(kind);
+                        operation_stats.fail_count.fetch_add(1, Ordering::SeqCst);
+                        operation_stats
+                            .failed_row_count
+                            .fetch_add(operation.row_count(), Ordering::SeqCst);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use hdrhistogram::Histogram;
+use serde::{Deserialize, Serialize};
+
+use crate::db::ErrorKind;
+
+// 마이크로초 단위로 기록 (1us ~ 1h, 유효숫자 3자리)
+const HIGHEST_TRACKABLE_US: u64 = 60 * 60 * 1_000_000;
+const SIGNIFICANT_FIGURES: u8 = 3;
+
+// worker 수만큼 히스토그램을 만들면 메모리가 너무 커지므로 상한을 둠
+const MAX_SHARDS: usize = 64;
+
+pub const PERCENTILES: [f64; 5] = [50.0, 90.0, 99.0, 99.9, 99.99];
+
+pub fn new_histogram() -> Histogram<u64> {
+    Histogram::new_with_bounds(1, HIGHEST_TRACKABLE_US, SIGNIFICANT_FIGURES)
+        .expect("Invalid histogram bounds")
+}
+
+/// Worker별 latency 히스토그램.
+/// 각 worker는 자기 슬롯에만 기록하고, 종료 후 merge해서 하나의 히스토그램으로 만든다.
+pub struct LatencyRecorder {
+    shards: Vec<Mutex<Histogram<u64>>>,
+}
+
+impl LatencyRecorder {
+    pub fn new(worker_count: usize) -> Self {
+        let shard_count = worker_count.clamp(1, MAX_SHARDS);
+
+        LatencyRecorder {
+            shards: (0..shard_count)
+                .map(|_| Mutex::new(new_histogram()))
+                .collect(),
+        }
+    }
+
+    pub fn record(&self, worker_id: usize, latency: Duration) {
+        let shard = &self.shards[worker_id % self.shards.len()];
+        shard
+            .lock()
+            .unwrap()
+            .saturating_record(latency.as_micros() as u64);
+    }
+
+    pub fn merge(&self) -> Histogram<u64> {
+        let mut merged = new_histogram();
+
+        for shard in &self.shards {
+            merged
+                .add(&*shard.lock().unwrap())
+                .expect("Failed to merge histogram");
+        }
+
+        merged
+    }
+
+    /// 지금까지 기록된 값을 합쳐서 반환하고 비움 (timeline의 구간별 percentile용)
+    pub fn drain(&self) -> Histogram<u64> {
+        let mut merged = new_histogram();
+
+        for shard in &self.shards {
+            let mut shard = shard.lock().unwrap();
+            merged.add(&*shard).expect("Failed to merge histogram");
+            shard.reset();
+        }
+
+        merged
+    }
+}
+
+pub fn us_to_ms(us: u64) -> f64 {
+    us as f64 / 1000.0
+}
+
+/// 초당 처리량 (warm-up이나 ramp 중에 phase가 끝나서 구간이 없으면 0)
+pub fn per_sec(count: u64, duration: Duration) -> f64 {
+    if duration.is_zero() {
+        return 0.0;
+    }
+
+    count as f64 / duration.as_secs_f64()
+}
+
+pub fn print_latency_report(histogram: &Histogram<u64>) {
+    if histogram.is_empty() {
+        println!("@ No latency samples recorded");
+        return;
+    }
+
+    println!("@ Max latency: {:.3} ms", us_to_ms(histogram.max()));
+    println!("@ Min latency: {:.3} ms", us_to_ms(histogram.min()));
+    println!("@ Avg latency: {:.3} ms", histogram.mean() / 1000.0);
+
+    for percentile in PERCENTILES {
+        println!(
+            "@ p{} latency: {:.3} ms",
+            percentile,
+            us_to_ms(histogram.value_at_percentile(percentile))
+        );
+    }
+}
+
+/// error 종류별 카운터
+#[derive(Default)]
+pub struct ErrorCounters {
+    counts: [AtomicU64; ErrorKind::ALL.len()],
+}
+
+impl ErrorCounters {
+    pub fn add(&self, kind: ErrorKind) {
+        self.counts[kind as usize].fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn total(&self) -> u64 {
+        self.counts
+            .iter()
+            .map(|count| count.load(Ordering::SeqCst))
+            .sum()
+    }
+
+    /// 0이 아닌 종류만
+    pub fn by_kind(&self) -> BTreeMap<ErrorKind, u64> {
+        ErrorKind::ALL
+            .into_iter()
+            .map(|kind| (kind, self.counts[kind as usize].load(Ordering::SeqCst)))
+            .filter(|(_, count)| *count > 0)
+            .collect()
+    }
+}
+
+/// 연산 종류(read/write)별 카운터와 latency 히스토그램
+pub struct OperationStats {
+    pub success_count: AtomicU64,
+    // 재시도 끝에 성공한 요청 수 (success_count에 포함)
+    pub retried_success_count: AtomicU64,
+    // 재시도를 모두 써도 실패해서 버려진 요청 수
+    pub fail_count: AtomicU64,
+    // 버려진 요청의 row 수
+    pub failed_row_count: AtomicU64,
+    pub not_found_count: AtomicU64,
+    // 성공한 요청이 처리한 row 수 (batch write는 batch 크기만큼 증가)
+    pub row_count: AtomicU64,
+    // 실패한 뒤 재시도한 시도 (종류별)
+    pub retries: ErrorCounters,
+    // 버려진 요청의 마지막 error (종류별)
+    pub failures: ErrorCounters,
+    pub latency: LatencyRecorder,
+}
+
+impl OperationStats {
+    pub fn new(worker_count: usize) -> Self {
+        OperationStats {
+            success_count: AtomicU64::new(0),
+            retried_success_count: AtomicU64::new(0),
+            fail_count: AtomicU64::new(0),
+            failed_row_count: AtomicU64::new(0),
+            not_found_count: AtomicU64::new(0),
+            row_count: AtomicU64::new(0),
+            retries: ErrorCounters::default(),
+            failures: ErrorCounters::default(),
+            latency: LatencyRecorder::new(worker_count),
+        }
+    }
+
+    pub fn success(&self) -> u64 {
+        self.success_count.load(Ordering::SeqCst)
+    }
+
+    pub fn fail(&self) -> u64 {
+        self.fail_count.load(Ordering::SeqCst)
+    }
+
+    // 실패한 시도 수 (재시도한 시도 + 버려진 요청의 마지막 시도)
+    pub fn errors(&self) -> u64 {
+        self.retries.total() + self.fail()
+    }
+
+    pub fn not_found(&self) -> u64 {
+        self.not_found_count.load(Ordering::SeqCst)
+    }
+
+    pub fn rows(&self) -> u64 {
+        self.row_count.load(Ordering::SeqCst)
+    }
+
+    pub fn summary(&self, duration: Duration) -> OperationSummary {
+        let histogram = self.latency.merge();
+
+        OperationSummary {
+            success_count: self.success(),
+            fail_count: self.fail(),
+            not_found_count: self.not_found(),
+            throughput: per_sec(self.success(), duration),
+            row_count: self.rows(),
+            row_throughput: per_sec(self.rows(), duration),
+            latency: LatencySummary::from_histogram(&histogram),
+            retry_count: self.retries.total(),
+            retried_success_count: self.retried_success_count.load(Ordering::SeqCst),
+            failed_row_count: self.failed_row_count.load(Ordering::SeqCst),
+            retries_by_kind: self.retries.by_kind(),
+            failures_by_kind: self.failures.by_kind(),
+        }
+    }
+
+    /// 재시도와 버려진 요청을 error 종류별로 출력 (없으면 출력하지 않음)
+    pub fn print_errors(&self) {
+        let format = |counts: BTreeMap<ErrorKind, u64>| {
+            counts
+                .into_iter()
+                .map(|(kind, count)| format!("{}: {}", kind.name(), count))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        if self.retries.total() > 0 {
+            println!(
+                "@ Retried attempts: {} ({})",
+                self.retries.total(),
+                format(self.retries.by_kind())
+            );
+            println!(
+                "@ Succeeded after retry: {}",
+                self.retried_success_count.load(Ordering::SeqCst)
+            );
+        }
+        if self.fail() > 0 {
+            println!(
+                "@ Permanently failed: {} requests, {} rows ({})",
+                self.fail(),
+                self.failed_row_count.load(Ordering::SeqCst),
+                format(self.failures.by_kind())
+            );
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OperationSummary {
+    pub success_count: u64,
+    pub fail_count: u64,
+    pub not_found_count: u64,
+    // 초당 성공한 요청 수
+    pub throughput: f64,
+    pub row_count: u64,
+    // 초당 처리한 row 수 (batch가 아니면 throughput과 같음)
+    pub row_throughput: f64,
+    pub latency: Option<LatencySummary>,
+    // 실패한 뒤 재시도한 시도 수
+    #[serde(default)]
+    pub retry_count: u64,
+    #[serde(default)]
+    pub retried_success_count: u64,
+    #[serde(default)]
+    pub failed_row_count: u64,
+    #[serde(default)]
+    pub retries_by_kind: BTreeMap<ErrorKind, u64>,
+    #[serde(default)]
+    pub failures_by_kind: BTreeMap<ErrorKind, u64>,
+}
+
+/// latency 요약 (ms 단위)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LatencySummary {
+    pub min_ms: f64,
+    pub max_ms: f64,
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+    pub p999_ms: f64,
+    pub p9999_ms: f64,
+}
+
+impl LatencySummary {
+    pub fn from_histogram(histogram: &Histogram<u64>) -> Option<Self> {
+        if histogram.is_empty() {
+            return None;
+        }
+
+        let percentile = |p: f64| us_to_ms(histogram.value_at_percentile(p));
+
+        Some(LatencySummary {
+            min_ms: us_to_ms(histogram.min()),
+            max_ms: us_to_ms(histogram.max()),
+            mean_ms: histogram.mean() / 1000.0,
+            p50_ms: percentile(50.0),
+            p90_ms: percentile(90.0),
+            p99_ms: percentile(99.0),
+            p999_ms: percentile(99.9),
+            p9999_ms: percentile(99.99),
+        })
+    }
+}
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+
+use crate::runner::PhaseSummary;
+
+// 최대 throughput의 이 비율에 처음 도달한 concurrency를 포화 지점으로 봄
+const SATURATION_RATIO: f64 = 0.9;
+
+/// concurrency 하나에서 load phase를 실행한 결과
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SweepPoint {
+    pub concurrency: usize,
+    pub duration_secs: f64,
+    pub rows_per_sec: f64,
+    pub p50_ms: Option<f64>,
+    pub p99_ms: Option<f64>,
+    pub p999_ms: Option<f64>,
+    pub fail_count: u64,
+}
+
+impl SweepPoint {
+    /// load phase는 Write 또는 WriteBatch 하나만 실행함
+    pub fn from_summary(concurrency: usize, summary: &PhaseSummary) -> Self {
+        let operation = summary.operations.values().next();
+        let latency = operation.and_then(|operation| operation.latency.as_ref());
+
+        SweepPoint {
+            concurrency,
+            duration_secs: summary.duration_secs,
+            rows_per_sec: operation.map_or(0.0, |operation| operation.row_throughput),
+            p50_ms: latency.map(|latency| latency.p50_ms),
+            p99_ms: latency.map(|latency| latency.p99_ms),
+            p999_ms: latency.map(|latency| latency.p999_ms),
+            fail_count: operation.map_or(0, |operation| operation.fail_count),
+        }
+    }
+}
+
+/// --sweep 결과: concurrency별 throughput/latency와 포화 지점
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SweepReport {
+    pub points: Vec<SweepPoint>,
+    // throughput이 가장 높았던 concurrency
+    pub best_concurrency: usize,
+    // 최대 throughput의 90%에 처음 도달한 concurrency (이후로는 latency만 늘어남)
+    pub saturation_concurrency: usize,
+}
+
+impl SweepReport {
+    pub fn new(points: Vec<SweepPoint>) -> Option<Self> {
+        let best = points
+            .iter()
+            .max_by(|a, b| a.rows_per_sec.total_cmp(&b.rows_per_sec))?;
+        let saturation = points
+            .iter()
+            .find(|point| point.rows_per_sec >= best.rows_per_sec * SATURATION_RATIO)?;
+
+        Some(SweepReport {
+            best_concurrency: best.concurrency,
+            saturation_concurrency: saturation.concurrency,
+            points,
+        })
+    }
+
+    pub fn best(&self) -> Option<&SweepPoint> {
+        self.points
+            .iter()
+            .find(|point| point.concurrency == self.best_concurrency)
+    }
+
+    pub fn print(&self) {
+        let format_ms = |value: Option<f64>| value.map_or("-".into(), |ms| format!("{:.3}", ms));
+
+        println!("@ Concurrency sweep");
+        println!("| Concurrency | rows/sec | p50 (ms) | p99 (ms) | p99.9 (ms) | Failed |");
+        println!("|---:|---:|---:|---:|---:|---:|");
+        for point in &self.points {
+            println!(
+                "| {} | {:.2} | {} | {} | {} | {} |",
+                point.concurrency,
+                point.rows_per_sec,
+                format_ms(point.p50_ms),
+                format_ms(point.p99_ms),
+                format_ms(point.p999_ms),
+                point.fail_count
+            );
+        }
+        println!(
+            "@ Best concurrency: {}, saturation point: {}",
+            self.best_concurrency, self.saturation_concurrency
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(concurrency: usize, rows_per_sec: f64) -> SweepPoint {
+        SweepPoint {
+            concurrency,
+            duration_secs: 1.0,
+            rows_per_sec,
+            p50_ms: None,
+            p99_ms: None,
+            p999_ms: None,
+            fail_count: 0,
+        }
+    }
+
+    #[test]
+    fn saturation_is_the_first_point_within_90_percent_of_the_best() {
+        let report = SweepReport::new(vec![
+            point(1, 100.0),
+            point(4, 850.0),
+            point(16, 950.0),
+            point(64, 1000.0),
+            point(256, 700.0),
+        ])
+        .unwrap();
+
+        assert_eq!(report.best_concurrency, 64);
+        assert_eq!(report.saturation_concurrency, 16);
+        assert_eq!(report.best().unwrap().rows_per_sec, 1000.0);
+    }
+
+    #[test]
+    fn exactly_90_percent_counts_as_saturated() {
+        let report = SweepReport::new(vec![point(1, 900.0), point(2, 1000.0)]).unwrap();
+
+        assert_eq!(report.saturation_concurrency, 1);
+    }
+
+    #[test]
+    fn single_point_is_both_best_and_saturation() {
+        let report = SweepReport::new(vec![point(8, 500.0)]).unwrap();
+
+        assert_eq!(report.best_concurrency, 8);
+        assert_eq!(report.saturation_concurrency, 8);
+    }
+
+    #[test]
+    fn no_points_means_no_report() {
+        assert!(SweepReport::new(vec![]).is_none());
+    }
+}
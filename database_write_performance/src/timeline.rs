@@ -0,0 +1,185 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tokio::time::MissedTickBehavior;
+
+use crate::runner::PhaseStats;
+use crate::stats::{new_histogram, us_to_ms};
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// timeline의 한 구간 (SAMPLE_INTERVAL 단위, 마지막 구간은 더 짧을 수 있음)
+#[derive(Clone, Debug, Serialize)]
+pub struct TimelineSample {
+    pub backend: String,
+    pub phase: String,
+    // phase 시작부터 구간 끝까지의 시간
+    pub elapsed_secs: f64,
+    // 구간 동안 성공한 요청/row 수를 초당으로 환산
+    pub requests_per_sec: f64,
+    pub rows_per_sec: f64,
+    // 구간 동안 실패한 시도 수
+    pub errors: u64,
+    // 구간 끝 시점에 실행 중인 요청 수
+    pub in_flight: u64,
+    // 구간 안에 완료된 요청이 없으면 None
+    pub p50_ms: Option<f64>,
+    pub p90_ms: Option<f64>,
+    pub p99_ms: Option<f64>,
+    pub max_ms: Option<f64>,
+}
+
+// phase 시작부터 누적된 카운터
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Totals {
+    success: u64,
+    rows: u64,
+    // 실패한 시도 (재시도 포함)
+    errors: u64,
+}
+
+impl Totals {
+    // warm-up, ramp 단계, steady state의 stats를 합산
+    fn read(stages: &[Arc<PhaseStats>]) -> Self {
+        stages
+            .iter()
+            .flat_map(|phase_stats| phase_stats.all())
+            .fold(
+                Totals {
+                    success: 0,
+                    rows: 0,
+                    errors: 0,
+                },
+                |totals, (_, operation_stats)| Totals {
+                    success: totals.success + operation_stats.success(),
+                    rows: totals.rows + operation_stats.rows(),
+                    errors: totals.errors + operation_stats.errors(),
+                },
+            )
+    }
+}
+
+/// stop을 받을 때까지 1초마다 stages를 합쳐서 sampling
+/// (backend, phase 이름은 비워두고 호출한 쪽에서 채움)
+pub fn spawn_sampler(
+    stages: Vec<Arc<PhaseStats>>,
+    request_count: Arc<AtomicU64>,
+    done_count: Arc<AtomicU64>,
+    mut stop: tokio::sync::oneshot::Receiver<()>,
+) -> tokio::task::JoinHandle<Vec<TimelineSample>> {
+    tokio::spawn(async move {
+        let start = Instant::now();
+
+        let mut interval =
+            tokio::time::interval_at((start + SAMPLE_INTERVAL).into(), SAMPLE_INTERVAL);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut samples = vec![];
+        let mut previous = Totals::read(&stages);
+        let mut previous_at = start;
+
+        loop {
+            let stopped = tokio::select! {
+                _ = interval.tick() => false,
+                _ = &mut stop => true,
+            };
+
+            let now = Instant::now();
+            let totals = Totals::read(&stages);
+
+            // 마지막 구간은 변화가 있을 때만 기록
+            if stopped && totals == previous {
+                break;
+            }
+
+            let seconds = (now - previous_at).as_secs_f64();
+            let mut histogram = new_histogram();
+            for phase_stats in &stages {
+                histogram
+                    .add(phase_stats.interval_latency.drain())
+                    .expect("Failed to merge histogram");
+            }
+            let percentile = |p: f64| {
+                (!histogram.is_empty()).then(|| us_to_ms(histogram.value_at_percentile(p)))
+            };
+
+            samples.push(TimelineSample {
+                backend: String::new(),
+                phase: String::new(),
+                elapsed_secs: (now - start).as_secs_f64(),
+                requests_per_sec: (totals.success - previous.success) as f64 / seconds,
+                rows_per_sec: (totals.rows - previous.rows) as f64 / seconds,
+                errors: totals.errors - previous.errors,
+                in_flight: request_count
+                    .load(Ordering::SeqCst)
+                    .saturating_sub(done_count.load(Ordering::SeqCst)),
+                p50_ms: percentile(50.0),
+                p90_ms: percentile(90.0),
+                p99_ms: percentile(99.0),
+                max_ms: (!histogram.is_empty()).then(|| us_to_ms(histogram.max())),
+            });
+
+            if stopped {
+                break;
+            }
+
+            previous = totals;
+            previous_at = now;
+        }
+
+        samples
+    })
+}
+
+/// 확장자가 .json이면 JSON 배열, 그 외에는 CSV로 저장
+pub fn write(path: &Path, samples: &[TimelineSample]) -> std::io::Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+
+    if path
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        serde_json::to_writer_pretty(&mut file, samples)?;
+    } else {
+        writeln!(
+            file,
+            "backend,phase,elapsed_secs,requests_per_sec,rows_per_sec,errors,in_flight,p50_ms,p90_ms,p99_ms,max_ms"
+        )?;
+
+        for sample in samples {
+            writeln!(
+                file,
+                "{},{},{:.3},{:.2},{:.2},{},{},{},{},{},{}",
+                quote(&sample.backend),
+                quote(&sample.phase),
+                sample.elapsed_secs,
+                sample.requests_per_sec,
+                sample.rows_per_sec,
+                sample.errors,
+                sample.in_flight,
+                optional(sample.p50_ms),
+                optional(sample.p90_ms),
+                optional(sample.p99_ms),
+                optional(sample.max_ms),
+            )?;
+        }
+    }
+
+    file.flush()
+}
+
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "\"\""))
+}
+
+// 값이 없으면 빈 칸
+fn optional(value: Option<f64>) -> String {
+    value
+        .map(|value| format!("{:.3}", value))
+        .unwrap_or_default()
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::db::{Database, Errors};
+use crate::interrupt::Interrupt;
+use crate::retry::RetryPolicy;
+use crate::workload;
+
+/// load phase 이후 dataset의 key를 모두 다시 읽어서 확인한 결과
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct VerifyReport {
+    // 확인한 key 수 (dataset의 중복 key는 한 번만 확인)
+    pub key_count: u64,
+    pub ok: u64,
+    // 값이 없음
+    pub missing: u64,
+    // 같은 key에 먼저 쓴 값이 남아 있음
+    pub stale: u64,
+    // 그 key에 쓴 적이 없는 값
+    pub corrupted: u64,
+    // 재시도 후에도 read가 실패한 key
+    pub read_errors: u64,
+    pub duration_secs: f64,
+    // backend가 보고한 row 수 (count를 지원하지 않으면 None)
+    pub backend_row_count: Option<u64>,
+    // interrupt로 모든 key를 확인하기 전에 멈춤
+    #[serde(default)]
+    pub interrupted: bool,
+}
+
+impl VerifyReport {
+    // 결과를 받은 key 수
+    pub fn checked_count(&self) -> u64 {
+        self.ok + self.missing + self.stale + self.corrupted + self.read_errors
+    }
+
+    pub fn print(&self) {
+        if self.interrupted {
+            println!(
+                "@ Interrupted: verified {} of {} keys in {:.2} s",
+                self.checked_count(),
+                self.key_count,
+                self.duration_secs
+            );
+        } else {
+            println!(
+                "@ Verified {} keys in {:.2} s",
+                self.key_count, self.duration_secs
+            );
+        }
+        println!(
+            "@ OK: {}, Missing: {}, Stale: {}, Corrupted: {}, Read errors: {}",
+            self.ok, self.missing, self.stale, self.corrupted, self.read_errors
+        );
+        match self.backend_row_count {
+            Some(count) => println!(
+                "@ Backend row count: {} (expected {})",
+                count, self.key_count
+            ),
+            None if self.interrupted => {}
+            None => println!("@ Backend row count: not supported"),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Counters {
+    ok: AtomicU64,
+    missing: AtomicU64,
+    stale: AtomicU64,
+    corrupted: AtomicU64,
+    read_errors: AtomicU64,
+}
+
+/// load phase에서 쓴 모든 key를 최대 worker_count개씩 동시에 읽어서 마지막으로 쓴 값과 비교
+///
+/// keys는 쓴 순서대로 (key, value hash). 값 자체 대신 hash를 비교함.
+/// interrupt를 받으면 진행 중인 read를 기다리지 않고 그때까지 확인한 결과를 반환
+pub async fn run(
+    db: Arc<dyn Database + Send + Sync>,
+    keys: &[(String, u64)],
+    worker_count: usize,
+    retry: RetryPolicy,
+    interrupt: &Interrupt,
+) -> VerifyReport {
+    let start = Instant::now();
+
+    // key별로 쓴 순서대로 값의 hash 목록 (마지막 값이 기대값)
+    let mut written: HashMap<&str, Vec<u64>> = HashMap::new();
+    for (key, value_hash) in keys {
+        written.entry(key.as_str()).or_default().push(*value_hash);
+    }
+
+    let counters = Counters::default();
+    let db = db.as_ref();
+
+    let reads = futures::stream::iter(written.iter()).for_each_concurrent(
+        worker_count.max(1),
+        |(key, values)| {
+            let counters = &counters;
+
+            async move {
+                let counter = match read_with_retry(db, key, retry).await {
+                    Ok(Some(value)) if values.last() == Some(&workload::value_hash(&value)) => {
+                        &counters.ok
+                    }
+                    Ok(Some(value)) if values.contains(&workload::value_hash(&value)) => {
+                        &counters.stale
+                    }
+                    Ok(Some(_)) => &counters.corrupted,
+                    Ok(None) => &counters.missing,
+                    Err(_) => &counters.read_errors,
+                };
+
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        },
+    );
+
+    let interrupted = interrupt.race(reads).await.is_none();
+    let backend_row_count = if interrupted {
+        None
+    } else {
+        interrupt
+            .race(db.count())
+            .await
+            .and_then(|count| count.ok())
+    };
+
+    VerifyReport {
+        key_count: written.len() as u64,
+        ok: counters.ok.load(Ordering::SeqCst),
+        missing: counters.missing.load(Ordering::SeqCst),
+        stale: counters.stale.load(Ordering::SeqCst),
+        corrupted: counters.corrupted.load(Ordering::SeqCst),
+        read_errors: counters.read_errors.load(Ordering::SeqCst),
+        duration_secs: start.elapsed().as_secs_f64(),
+        backend_row_count,
+        interrupted: interrupted || interrupt.is_set(),
+    }
+}
+
+async fn read_with_retry(
+    db: &(dyn Database + Send + Sync),
+    key: &str,
+    retry: RetryPolicy,
+) -> Result<Option<String>, Errors> {
+    let start = Instant::now();
+    let mut attempt = 0;
+
+    loop {
+        attempt += 1;
+
+        match db.read(key).await {
+            Ok(value) => return Ok(value),
+            Err(error) => match retry.backoff(attempt, start.elapsed(), error.kind()) {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(error),
+            },
+        }
+    }
+}
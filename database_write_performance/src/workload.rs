@@ -0,0 +1,214 @@
+use std::sync::Arc;
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::distribution::KeyDistribution;
+use crate::runner::Operation;
+use crate::ycsb::CoreWorkload;
+
+#[derive(Clone)]
+pub struct WriteEntry {
+    pub key: String,
+    pub value: String,
+}
+
+// update에 재사용할 value 표본 수
+pub const VALUE_SAMPLE_SIZE: usize = 10000;
+
+/// load phase에서 쓴 key와 update에 재사용할 value 표본
+///
+/// dataset 전체를 메모리에 두지 않도록 value는 앞쪽 VALUE_SAMPLE_SIZE개만 보관하고,
+/// verify는 key마다 쓴 value의 hash로 비교함
+#[derive(Clone, Debug, Default)]
+pub struct Written {
+    // 쓴 순서대로 (key, value hash)
+    pub keys: Vec<(String, u64)>,
+    pub values: Vec<String>,
+}
+
+impl Written {
+    pub fn push(&mut self, entry: &WriteEntry) {
+        self.keys
+            .push((entry.key.clone(), value_hash(&entry.value)));
+        if self.values.len() < VALUE_SAMPLE_SIZE {
+            self.values.push(entry.value.clone());
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+/// verify에서 비교할 value hash (한 process 안에서는 같은 값이면 같은 hash)
+pub fn value_hash(value: &str) -> u64 {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// load 이후에 실행하는 phase
+#[derive(Clone, Copy, Debug)]
+pub enum Phase {
+    // read_ratio% read, 나머지는 update (key는 균등 분포)
+    Mixed { read_ratio: u32 },
+    // YCSB core workload
+    Ycsb(CoreWorkload),
+}
+
+impl Phase {
+    /// "95" 같은 read 비율, 또는 "ycsb-a", "ycsb-b:uniform" 같은 YCSB workload
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+
+        if let Some(rest) = text.strip_prefix("ycsb-") {
+            let (name, distribution) = match rest.split_once(':') {
+                Some((name, distribution)) => (name, Some(distribution)),
+                None => (rest, None),
+            };
+
+            let mut workload = CoreWorkload::parse(&name.to_lowercase())?;
+            if let Some(distribution) = distribution {
+                workload.request_distribution = KeyDistribution::parse(distribution)?;
+            }
+
+            return Some(Phase::Ycsb(workload));
+        }
+
+        let read_ratio: u32 = text.parse().ok()?;
+        if read_ratio > 100 {
+            return None;
+        }
+
+        Some(Phase::Mixed { read_ratio })
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Phase::Mixed { read_ratio } => {
+                format!("read {}% / write {}%", read_ratio, 100 - read_ratio)
+            }
+            Phase::Ycsb(workload) => format!(
+                "YCSB workload {} ({})",
+                workload.name,
+                workload.request_distribution.name()
+            ),
+        }
+    }
+
+    pub fn uses_scan(&self) -> bool {
+        match self {
+            Phase::Mixed { .. } => false,
+            Phase::Ycsb(workload) => workload.uses_scan(),
+        }
+    }
+
+    /// phase에 맞는 operation을 sender로 보내는 producer를 실행
+    pub fn spawn_producer(
+        self,
+        written: Arc<Written>,
+        operation_count: usize,
+        sender: tokio::sync::mpsc::Sender<Operation>,
+    ) {
+        tokio::spawn(async move {
+            match self {
+                Phase::Mixed { read_ratio } => {
+                    produce_mixed(read_ratio, &written, operation_count, sender).await
+                }
+                Phase::Ycsb(workload) => {
+                    crate::ycsb::produce(workload, &written, operation_count, sender).await
+                }
+            }
+        });
+    }
+}
+
+// load된 key 중에서 균등하게 선택
+async fn produce_mixed(
+    read_ratio: u32,
+    written: &Written,
+    operation_count: usize,
+    sender: tokio::sync::mpsc::Sender<Operation>,
+) {
+    let mut rng = StdRng::from_os_rng();
+
+    for i in 0..operation_count {
+        if i % 10000 == 0 {
+            eprintln!("Running {} operations", i);
+        }
+
+        let (key, _) = &written.keys[rng.random_range(0..written.keys.len())];
+
+        // update는 load한 value 중 하나를 임의로 재사용
+        let operation = if rng.random_range(0..100) < read_ratio {
+            Operation::Read { key: key.clone() }
+        } else {
+            Operation::Write {
+                key: key.clone(),
+                value: written.values[rng.random_range(0..written.values.len())].clone(),
+            }
+        };
+
+        if sender.send(operation).await.is_err() {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_read_ratio() {
+        assert!(matches!(
+            Phase::parse("95"),
+            Some(Phase::Mixed { read_ratio: 95 })
+        ));
+        assert!(matches!(
+            Phase::parse(" 100 "),
+            Some(Phase::Mixed { read_ratio: 100 })
+        ));
+        assert!(matches!(
+            Phase::parse("0"),
+            Some(Phase::Mixed { read_ratio: 0 })
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_read_ratio() {
+        assert!(Phase::parse("101").is_none());
+        assert!(Phase::parse("-5").is_none());
+        assert!(Phase::parse("half").is_none());
+        assert!(Phase::parse("").is_none());
+    }
+
+    #[test]
+    fn parses_ycsb_workload_with_default_distribution() {
+        let Some(Phase::Ycsb(workload)) = Phase::parse("ycsb-A") else {
+            panic!("ycsb-A is not a YCSB phase");
+        };
+
+        assert_eq!(workload.name, "A");
+        assert_eq!(workload.request_distribution, KeyDistribution::Zipfian);
+    }
+
+    #[test]
+    fn ycsb_distribution_overrides_the_workload_default() {
+        let Some(Phase::Ycsb(workload)) = Phase::parse("ycsb-d:uniform") else {
+            panic!("ycsb-d:uniform is not a YCSB phase");
+        };
+
+        assert_eq!(workload.name, "D");
+        assert_eq!(workload.request_distribution, KeyDistribution::Uniform);
+    }
+
+    #[test]
+    fn rejects_unknown_ycsb_workload_or_distribution() {
+        assert!(Phase::parse("ycsb-z").is_none());
+        assert!(Phase::parse("ycsb-a:gaussian").is_none());
+        assert!(Phase::parse("ycsb-").is_none());
+    }
+}
@@ -0,0 +1,153 @@
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::distribution::{KeyChooser, KeyDistribution};
+use crate::runner::Operation;
+use crate::workload::Written;
+
+// YCSB 기본값 (scanlengthdistribution=uniform, maxscanlength=100)
+pub const MAX_SCAN_LENGTH: usize = 100;
+
+/// YCSB core workload A~F
+#[derive(Clone, Copy, Debug)]
+pub struct CoreWorkload {
+    pub name: &'static str,
+    pub read_proportion: f64,
+    pub update_proportion: f64,
+    pub insert_proportion: f64,
+    pub scan_proportion: f64,
+    pub read_modify_write_proportion: f64,
+    pub request_distribution: KeyDistribution,
+}
+
+impl CoreWorkload {
+    pub fn parse(name: &str) -> Option<Self> {
+        let workload = match name {
+            // update heavy
+            "a" => CoreWorkload {
+                name: "A",
+                read_proportion: 0.5,
+                update_proportion: 0.5,
+                ..CoreWorkload::empty()
+            },
+            // read mostly
+            "b" => CoreWorkload {
+                name: "B",
+                read_proportion: 0.95,
+                update_proportion: 0.05,
+                ..CoreWorkload::empty()
+            },
+            // read only
+            "c" => CoreWorkload {
+                name: "C",
+                read_proportion: 1.0,
+                ..CoreWorkload::empty()
+            },
+            // read latest
+            "d" => CoreWorkload {
+                name: "D",
+                read_proportion: 0.95,
+                insert_proportion: 0.05,
+                request_distribution: KeyDistribution::Latest,
+                ..CoreWorkload::empty()
+            },
+            // short ranges
+            "e" => CoreWorkload {
+                name: "E",
+                scan_proportion: 0.95,
+                insert_proportion: 0.05,
+                ..CoreWorkload::empty()
+            },
+            // read-modify-write
+            "f" => CoreWorkload {
+                name: "F",
+                read_proportion: 0.5,
+                read_modify_write_proportion: 0.5,
+                ..CoreWorkload::empty()
+            },
+            _ => return None,
+        };
+
+        Some(workload)
+    }
+
+    fn empty() -> Self {
+        CoreWorkload {
+            name: "",
+            read_proportion: 0.0,
+            update_proportion: 0.0,
+            insert_proportion: 0.0,
+            scan_proportion: 0.0,
+            read_modify_write_proportion: 0.0,
+            request_distribution: KeyDistribution::Zipfian,
+        }
+    }
+
+    pub fn uses_scan(&self) -> bool {
+        self.scan_proportion > 0.0
+    }
+}
+
+/// load된 key를 대상으로 workload에 맞는 operation을 operation_count개 생성
+pub async fn produce(
+    workload: CoreWorkload,
+    written: &Written,
+    operation_count: usize,
+    sender: tokio::sync::mpsc::Sender<Operation>,
+) {
+    let mut rng = StdRng::from_os_rng();
+
+    // insert된 key도 이후 선택 대상이 되도록 key 목록을 따로 관리
+    // (아직 완료되지 않은 insert의 key가 선택되면 not found로 집계될 수 있음)
+    let mut keys: Vec<String> = written.keys.iter().map(|(key, _)| key.clone()).collect();
+    let mut chooser = KeyChooser::new(workload.request_distribution, keys.len());
+
+    for i in 0..operation_count {
+        if i % 10000 == 0 {
+            eprintln!("Running {} operations", i);
+        }
+
+        // value는 load한 값 중 하나를 임의로 재사용
+        let value = written.values[rng.random_range(0..written.values.len())].clone();
+
+        // 누적 비율로 operation 종류 결정
+        let choice: f64 = rng.random();
+        let read_threshold = workload.read_proportion;
+        let update_threshold = read_threshold + workload.update_proportion;
+        let scan_threshold = update_threshold + workload.scan_proportion;
+        let read_modify_write_threshold = scan_threshold + workload.read_modify_write_proportion;
+
+        let operation = if choice < read_threshold {
+            Operation::Read {
+                key: choose_key(&mut chooser, &mut rng, &keys),
+            }
+        } else if choice < update_threshold {
+            Operation::Write {
+                key: choose_key(&mut chooser, &mut rng, &keys),
+                value,
+            }
+        } else if choice < scan_threshold {
+            Operation::Scan {
+                start_key: choose_key(&mut chooser, &mut rng, &keys),
+                count: rng.random_range(1..=MAX_SCAN_LENGTH),
+            }
+        } else if choice < read_modify_write_threshold {
+            Operation::ReadModifyWrite {
+                key: choose_key(&mut chooser, &mut rng, &keys),
+                value,
+            }
+        } else {
+            let key = uuid::Uuid::new_v4().to_string();
+            keys.push(key.clone());
+
+            Operation::Write { key, value }
+        };
+
+        if sender.send(operation).await.is_err() {
+            break;
+        }
+    }
+}
+
+fn choose_key(chooser: &mut KeyChooser, rng: &mut StdRng, keys: &[String]) -> String {
+    keys[chooser.next_index(rng, keys.len())].clone()
+}
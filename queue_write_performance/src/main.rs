@@ -13,7 +13,7 @@ async fn main() {
     // parse args
     let args: Vec<String> = std::env::args().collect();
     if args.len() != 2 {
-        eprintln!("Usage: {} <queue_type>", args[0]);
+        eprintln!("Usage: {} <postgres|kafka|fake[://?options]>", args[0]);
         std::process::exit(1);
     }
 
@@ -41,6 +41,7 @@ async fn main() {
                 println!("Writing {} lines", i);
             }
 
+            #[allow(clippy::manual_split_once)]
             let mut parts = line.splitn(2, ',');
             let key = parts.next().unwrap();
             let value = parts.next().unwrap();
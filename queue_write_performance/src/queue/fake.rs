@@ -0,0 +1,333 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use tokio::sync::Semaphore;
+
+use super::{Errors, Queue, Result};
+
+/// 응답 시간 분포 (모든 값은 ms)
+#[derive(Clone, Copy, Debug)]
+pub enum Latency {
+    Constant {
+        latency_ms: f64,
+    },
+    Normal {
+        mean_ms: f64,
+        stddev_ms: f64,
+    },
+    // median = exp(mu)
+    LogNormal {
+        median_ms: f64,
+        sigma: f64,
+    },
+    // slow_rate 비율의 요청만 slow_ms로 응답 (GC, broker rebalance 등)
+    Bimodal {
+        fast_ms: f64,
+        slow_ms: f64,
+        slow_rate: f64,
+    },
+}
+
+impl Latency {
+    fn sample<R: Rng>(&self, rng: &mut R) -> Duration {
+        let millis = match *self {
+            Latency::Constant { latency_ms } => latency_ms,
+            Latency::Normal { mean_ms, stddev_ms } => mean_ms + stddev_ms * standard_normal(rng),
+            Latency::LogNormal { median_ms, sigma } => {
+                median_ms * (sigma * standard_normal(rng)).exp()
+            }
+            Latency::Bimodal {
+                fast_ms,
+                slow_ms,
+                slow_rate,
+            } => {
+                if rng.random::<f64>() < slow_rate {
+                    slow_ms
+                } else {
+                    fast_ms
+                }
+            }
+        };
+
+        Duration::from_secs_f64(millis.max(0.0) / 1000.0)
+    }
+}
+
+// Box-Muller 변환
+fn standard_normal<R: Rng>(rng: &mut R) -> f64 {
+    let u1: f64 = 1.0 - rng.random::<f64>();
+    let u2: f64 = rng.random();
+
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// 주기적으로 모든 요청이 멈추는 구간 (segment roll, checkpoint 흉내)
+#[derive(Clone, Copy, Debug)]
+pub struct Stall {
+    pub every: Duration,
+    pub duration: Duration,
+}
+
+/// simulated queue 설정. queue type 인자의 query string으로 지정한다.
+///
+/// ```text
+/// fake://?latency=lognormal&latency_ms=5&sigma=0.8&error_rate=0.01&stall_every_ms=10000&stall_ms=500&capacity=100
+/// ```
+#[derive(Clone, Debug)]
+pub struct Profile {
+    pub latency: Latency,
+    // 요청이 실패할 확률 (0.0 ~ 1.0)
+    pub error_rate: f64,
+    pub stall: Option<Stall>,
+    // 동시에 처리하는 최대 요청 수. 넘는 요청은 대기열에서 기다림
+    pub capacity: Option<usize>,
+    pub seed: Option<u64>,
+}
+
+impl Profile {
+    /// "fake" 또는 "fake://?..." (option이 없으면 5 ms constant latency)
+    pub fn parse(url: &str) -> Result<Self> {
+        let url = if url == "fake" { "fake://" } else { url };
+        let url = url::Url::parse(url)
+            .map_err(|e| Errors::ConnectionError(format!("Invalid url: {}", e)))?;
+
+        let mut params: BTreeMap<String, String> = url.query_pairs().into_owned().collect();
+
+        // queue의 Errors는 종류를 나누지 않으므로 error_kind는 받지 않음
+        if params.contains_key("error_kind") {
+            return Err(Errors::ConnectionError(
+                "error_kind is not supported by the fake queue (writes fail with WriteError)"
+                    .into(),
+            ));
+        }
+
+        let latency_ms = number(&mut params, "latency_ms", 5.0)?;
+        let latency = match params.remove("latency").as_deref() {
+            None | Some("constant") => Latency::Constant { latency_ms },
+            Some("normal") => Latency::Normal {
+                mean_ms: latency_ms,
+                stddev_ms: number(&mut params, "stddev_ms", latency_ms / 4.0)?,
+            },
+            Some("lognormal") => Latency::LogNormal {
+                median_ms: latency_ms,
+                sigma: number(&mut params, "sigma", 0.5)?,
+            },
+            Some("bimodal") => Latency::Bimodal {
+                fast_ms: latency_ms,
+                slow_ms: number(&mut params, "slow_ms", latency_ms * 10.0)?,
+                slow_rate: number(&mut params, "slow_rate", 0.05)?.min(1.0),
+            },
+            Some(other) => {
+                return Err(Errors::ConnectionError(format!(
+                    "Unknown latency distribution: {}",
+                    other
+                )));
+            }
+        };
+
+        let error_rate = number(&mut params, "error_rate", 0.0)?.min(1.0);
+
+        let stall_every_ms = number(&mut params, "stall_every_ms", 0.0)?;
+        let stall_ms = number(&mut params, "stall_ms", 0.0)?;
+        // 주기가 1 ms보다 짧으면 Duration으로 바꿀 때 0이 될 수 있으므로 1 ms로 올림
+        let stall = (stall_every_ms > 0.0 && stall_ms > 0.0).then(|| {
+            let stall_every_ms = stall_every_ms.max(1.0);
+            Stall {
+                every: Duration::from_secs_f64(stall_every_ms / 1000.0),
+                duration: Duration::from_secs_f64(stall_ms.min(stall_every_ms) / 1000.0),
+            }
+        });
+
+        let capacity = match number(&mut params, "capacity", 0.0)? as usize {
+            0 => None,
+            capacity => Some(capacity),
+        };
+
+        let seed = match params.remove("seed") {
+            Some(value) => Some(
+                value
+                    .parse::<u64>()
+                    .map_err(|_| Errors::ConnectionError(format!("Invalid seed: {}", value)))?,
+            ),
+            None => None,
+        };
+
+        // 오타로 설정이 무시되지 않도록 모르는 값은 에러
+        if let Some(name) = params.keys().next() {
+            return Err(Errors::ConnectionError(format!(
+                "Unknown fake queue option: {}",
+                name
+            )));
+        }
+
+        Ok(Profile {
+            latency,
+            error_rate,
+            stall,
+            capacity,
+            seed,
+        })
+    }
+}
+
+// 0 이상의 숫자 option (없으면 default)
+fn number(params: &mut BTreeMap<String, String>, name: &str, default: f64) -> Result<f64> {
+    match params.remove(name) {
+        Some(value) => match value.parse::<f64>() {
+            Ok(number) if number.is_finite() && number >= 0.0 => Ok(number),
+            _ => Err(Errors::ConnectionError(format!(
+                "Invalid {}: {}",
+                name, value
+            ))),
+        },
+        None => Ok(default),
+    }
+}
+
+/// broker 없이 driver를 검증하기 위한 simulated queue
+pub struct FakeQueue {
+    profile: Profile,
+    rng: Mutex<StdRng>,
+    capacity: Option<Semaphore>,
+    started: Instant,
+}
+
+impl FakeQueue {
+    pub async fn new(url: &str) -> Result<Arc<dyn Queue + Send + Sync>> {
+        let profile = Profile::parse(url)?;
+        let rng = match profile.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_rng(&mut rand::rng()),
+        };
+
+        Ok(Arc::new(FakeQueue {
+            capacity: profile.capacity.map(Semaphore::new),
+            profile,
+            rng: Mutex::new(rng),
+            started: Instant::now(),
+        }))
+    }
+
+    // 매 주기의 마지막 stall.duration 동안 멈춤. stall 구간이면 끝날 때까지 남은 시간
+    fn stall_remaining(&self) -> Option<Duration> {
+        let stall = self.profile.stall?;
+        let elapsed = self.started.elapsed().as_nanos() % stall.every.as_nanos();
+        let remaining = stall.every - Duration::from_nanos(elapsed as u64);
+
+        (remaining <= stall.duration).then_some(remaining)
+    }
+}
+
+#[async_trait::async_trait]
+impl Queue for FakeQueue {
+    async fn ping(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn setup(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// 요청 하나를 처리하는 시간만큼 대기하고, error_rate 확률로 실패
+    async fn write(&self, _key: &str, _value: &str) -> Result<()> {
+        let _permit = match &self.capacity {
+            Some(semaphore) => Some(semaphore.acquire().await.unwrap()),
+            None => None,
+        };
+
+        if let Some(remaining) = self.stall_remaining() {
+            tokio::time::sleep(remaining).await;
+        }
+
+        let (latency, failed) = {
+            let mut rng = self.rng.lock().unwrap();
+            (
+                self.profile.latency.sample(&mut *rng),
+                rng.random::<f64>() < self.profile.error_rate,
+            )
+        };
+
+        tokio::time::sleep(latency).await;
+
+        if failed {
+            return Err(Errors::WriteError);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bare_fake_uses_the_defaults() {
+        let profile = Profile::parse("fake").unwrap();
+
+        assert!(matches!(
+            profile.latency,
+            Latency::Constant { latency_ms } if latency_ms == 5.0
+        ));
+        assert_eq!(profile.error_rate, 0.0);
+        assert!(profile.stall.is_none());
+        assert!(profile.capacity.is_none());
+        assert!(profile.seed.is_none());
+    }
+
+    #[test]
+    fn parses_every_option() {
+        let profile = Profile::parse(
+            "fake://?latency=lognormal&latency_ms=5&sigma=0.8&error_rate=0.01\
+             &stall_every_ms=10000&stall_ms=500&capacity=100&seed=7",
+        )
+        .unwrap();
+
+        assert!(matches!(
+            profile.latency,
+            Latency::LogNormal { median_ms, sigma } if median_ms == 5.0 && sigma == 0.8
+        ));
+        assert_eq!(profile.error_rate, 0.01);
+        let stall = profile.stall.unwrap();
+        assert_eq!(stall.every, Duration::from_secs(10));
+        assert_eq!(stall.duration, Duration::from_millis(500));
+        assert_eq!(profile.capacity, Some(100));
+        assert_eq!(profile.seed, Some(7));
+    }
+
+    #[test]
+    fn decodes_percent_encoded_values() {
+        let profile = Profile::parse("fake://?latency=%62imodal&slow%5Frate=0%2E5").unwrap();
+
+        assert!(matches!(
+            profile.latency,
+            Latency::Bimodal { slow_rate, .. } if slow_rate == 0.5
+        ));
+    }
+
+    #[test]
+    fn stall_period_is_at_least_one_millisecond() {
+        let profile = Profile::parse("fake://?stall_every_ms=0.0000001&stall_ms=5").unwrap();
+        let stall = profile.stall.unwrap();
+
+        assert_eq!(stall.every, Duration::from_millis(1));
+        assert_eq!(stall.duration, Duration::from_millis(1));
+    }
+
+    #[test]
+    fn rejects_invalid_options() {
+        for url in [
+            "fake://?latency=uniform",
+            "fake://?error_kind=timeout",
+            "fake://?latency_ms=-1",
+            "fake://?seed=abc",
+            "fake://?latancy_ms=5",
+        ] {
+            assert!(Profile::parse(url).is_err(), "{} was accepted", url);
+        }
+    }
+}
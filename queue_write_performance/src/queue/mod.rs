@@ -1,5 +1,9 @@
+// 각 queue의 new()는 Self 대신 Arc<dyn Queue>를 반환함
+#![allow(clippy::new_ret_no_self)]
+
 use std::{fmt::Debug, sync::Arc};
 
+pub mod fake;
 pub mod kafka;
 pub mod postgres;
 
@@ -20,6 +24,8 @@ pub async fn new_queue(queue_type: &str) -> Result<Arc<dyn Queue + Send + Sync>>
     match queue_type {
         "postgres" => postgres::PostgresDB::new().await,
         "kafka" => kafka::KafkaQueue::new().await,
+        // latency, error, stall 설정은 query string으로 (예: fake://?latency_ms=5&error_rate=0.01)
+        url if url == "fake" || url.starts_with("fake://") => fake::FakeQueue::new(url).await,
         _ => Err(Errors::ConnectionError("Unknown database type".into())),
     }
 }
@@ -41,28 +47,3 @@ impl Debug for Errors {
 }
 
 pub type Result<T> = std::result::Result<T, Errors>;
-
-#[derive(Clone, Debug)]
-pub struct FakeQueue {}
-
-impl FakeQueue {
-    pub fn new() -> Arc<dyn Queue + Send + Sync> {
-        Arc::new(FakeQueue {})
-    }
-}
-
-#[async_trait::async_trait]
-impl Queue for FakeQueue {
-    async fn ping(&self) -> Result<()> {
-        Ok(())
-    }
-
-    async fn setup(&self) -> Result<()> {
-        Ok(())
-    }
-
-    async fn write(&self, _key: &str, _value: &str) -> Result<()> {
-        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
-        Ok(())
-    }
-}